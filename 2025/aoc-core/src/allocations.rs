@@ -0,0 +1,164 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::Phase;
+
+/// The system allocator, counting every allocation.
+///
+/// It is the global allocator of every day built with the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+fn record_allocation(size: usize) {
+    let size = size as u64;
+
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+fn record_deallocation(size: usize) {
+    LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        record_deallocation(layout.size());
+    }
+
+    // a reallocation counts as a new allocation of the new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+
+        if !new_ptr.is_null() {
+            record_deallocation(layout.size());
+            record_allocation(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+/// What a phase allocated, as counted by the [`CountingAllocator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseAllocations {
+    pub phase: Phase,
+    pub allocations: u64,
+    pub bytes: u64,
+    /// The most heap bytes allocated by the phase that were alive at the same time.
+    ///
+    /// This is not the peak resident set size: it leaves out the stack, the binary,
+    /// memory freed but kept by the system allocator, and heap pages that were never touched.
+    pub peak_heap_bytes: u64,
+}
+
+/// Runs `f` and counts its allocations, if the `alloc-stats` feature is enabled.
+pub fn measure_allocations<T>(
+    phase: Phase,
+    f: impl FnOnce() -> T,
+) -> (T, Option<PhaseAllocations>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = PhaseAllocations {
+        phase,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+        peak_heap_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes),
+    };
+
+    (result, Some(stats))
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `1.50 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.2} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting_allocator_works() {
+        let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+        let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+        let layout = Layout::from_size_align(64, 8).unwrap();
+
+        unsafe {
+            let ptr = CountingAllocator.alloc(layout);
+            let ptr = CountingAllocator.realloc(ptr, layout, 128);
+            CountingAllocator.dealloc(ptr, Layout::from_size_align(128, 8).unwrap());
+        }
+
+        assert!(ALLOCATIONS.load(Ordering::Relaxed) - allocations >= 2);
+        assert!(ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes >= 192);
+    }
+
+    #[test]
+    fn format_bytes_works() {
+        assert_eq!("0 B", format_bytes(0));
+        assert_eq!("1023 B", format_bytes(1023));
+        assert_eq!("1.50 KiB", format_bytes(1536));
+        assert_eq!("3.00 MiB", format_bytes(3 * 1024 * 1024));
+        assert_eq!("2048.00 TiB", format_bytes(2048 * 1024_u64.pow(4)));
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::Display;
+
+use num_bigint::{BigInt, BigUint};
+
+/// The answer to a part of a puzzle.
+///
+/// Most puzzles are answered with an integer, some with a text like a code of letters
+/// or a comma separated list. Every variant is displayed exactly as it has to be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Big(BigInt),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Big(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident, $target:ty, $($integer:ty),+) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(Signed, i64, i8, i16, i32, i64, isize);
+impl_from_integer!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Big(value.into())
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Big(value.into())
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Answer::Big(value.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_are_displayed_plainly() {
+        assert_eq!("-1086", Answer::from(-1086_i64).to_string());
+        assert_eq!(
+            "365804144481581",
+            Answer::from(365804144481581_u64).to_string()
+        );
+        assert_eq!("1508", Answer::from(1508_usize).to_string());
+        assert_eq!("8538", Answer::from(8538_u32).to_string());
+    }
+
+    #[test]
+    fn big_integers_are_displayed_plainly() {
+        let big = u128::MAX;
+        assert_eq!(Answer::Big(BigInt::from(big)), Answer::from(big));
+        assert_eq!(big.to_string(), Answer::from(big).to_string());
+        assert_eq!(
+            "-170141183460469231731687303715884105728",
+            Answer::from(i128::MIN).to_string()
+        );
+    }
+
+    #[test]
+    fn texts_are_displayed_unchanged() {
+        assert_eq!("EHZRPUKB", Answer::from("EHZRPUKB").to_string());
+        assert_eq!("6,0", Answer::from(format!("{},{}", 6, 0)).to_string());
+        assert_eq!("x", Answer::from('x').to_string());
+    }
+}
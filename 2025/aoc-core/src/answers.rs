@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use crate::Part;
+
+/// The answers of both parts of a day, as written in `answers.toml` and the example fixtures.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+}
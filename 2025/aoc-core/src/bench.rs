@@ -0,0 +1,177 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ParseError, Part, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl From<Part> for Phase {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Phase::PartOne,
+            Part::Two => Phase::PartTwo,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part one"),
+            Phase::PartTwo => write!(f, "part two"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: u32,
+    pub samples: u32,
+}
+
+/// The timings of all samples of a single phase, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseTimings {
+    pub phase: Phase,
+    pub samples: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl PhaseTimings {
+    fn from_samples(phase: Phase, mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is required");
+
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Self {
+            phase,
+            samples: samples.len() as u32,
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchReport {
+    pub phases: Vec<PhaseTimings>,
+}
+
+/// Times parsing and the given parts separately, each with its own warmup and samples.
+pub fn bench<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    config: &BenchConfig,
+) -> Result<BenchReport, ParseError> {
+    let parsed = S::parse(input)?;
+
+    let mut phases = vec![PhaseTimings::from_samples(
+        Phase::Parse,
+        measure(config, || S::parse(black_box(input))),
+    )];
+
+    for part in parts {
+        let samples = match part {
+            Part::One => measure(config, || S::part_one(black_box(&parsed))),
+            Part::Two => measure(config, || S::part_two(black_box(&parsed))),
+        };
+
+        phases.push(PhaseTimings::from_samples((*part).into(), samples));
+    }
+
+    Ok(BenchReport { phases })
+}
+
+fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    (0..config.samples)
+        .map(|_| {
+            let start = Instant::now();
+            let result = f();
+            let elapsed = start.elapsed();
+            black_box(result);
+            elapsed
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_timings_from_odd_number_of_samples_works() {
+        let samples = [5, 1, 3].map(Duration::from_nanos).to_vec();
+        let timings = PhaseTimings::from_samples(Phase::Parse, samples);
+        let expected = PhaseTimings {
+            phase: Phase::Parse,
+            samples: 3,
+            min_ns: 1,
+            median_ns: 3,
+            max_ns: 5,
+        };
+        assert_eq!(expected, timings);
+    }
+
+    #[test]
+    fn phase_timings_from_even_number_of_samples_works() {
+        let samples = [8, 2, 4, 6].map(Duration::from_nanos).to_vec();
+        let timings = PhaseTimings::from_samples(Phase::PartOne, samples);
+        assert_eq!(5, timings.median_ns);
+    }
+
+    #[test]
+    fn bench_report_serialization_works() {
+        let report = BenchReport {
+            phases: vec![PhaseTimings {
+                phase: Phase::PartTwo,
+                samples: 1,
+                min_ns: 2,
+                median_ns: 2,
+                max_ns: 2,
+            }],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        let expected =
+            r#"{"phases":[{"phase":"part-two","samples":1,"min_ns":2,"median_ns":2,"max_ns":2}]}"#;
+        assert_eq!(expected, json);
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+    }
+}
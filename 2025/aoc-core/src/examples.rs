@@ -0,0 +1,181 @@
+use std::{fs, path::Path};
+
+use crate::{Answers, Part, Solution};
+
+/// Generates a test that runs every example of the calling crate through both parts.
+///
+/// The examples live in the `examples` directory of the crate: `1.txt` is the puzzle input
+/// and `1.toml` holds the expected answers as `part-one = "..."` and `part-two = "..."`.
+/// A part without an expected answer is skipped.
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn examples_work() {
+            $crate::check_examples::<$solution>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+        }
+    };
+}
+
+/// Checks every example in the directory and panics with a summary of all mismatches.
+pub fn check_examples<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let examples = find_examples(dir);
+
+    assert!(!examples.is_empty(), "{} has no examples", dir.display());
+
+    let mut failures = Vec::new();
+
+    for name in examples {
+        let input = fs::read_to_string(dir.join(format!("{name}.txt")))
+            .unwrap_or_else(|error| panic!("example {name} should be readable: {error}"));
+        let expected = read_expected_answers(&dir.join(format!("{name}.toml")));
+
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                failures.push(format!(
+                    "example {name}: parsing failed: {}",
+                    error.render(&input)
+                ));
+                continue;
+            }
+        };
+
+        for part in Part::ALL {
+            let Some(expected) = expected.get(part) else {
+                continue;
+            };
+
+            let actual = match part {
+                Part::One => S::part_one(&parsed),
+                Part::Two => S::part_two(&parsed),
+            };
+
+            let actual = match actual {
+                Ok(actual) => actual.to_string(),
+                Err(error) => {
+                    failures.push(format!(
+                        "example {name}, part {part}: solving failed: {error}"
+                    ));
+                    continue;
+                }
+            };
+
+            if actual != expected {
+                failures.push(format!(
+                    "example {name}, part {part}: expected {expected}, got {actual}"
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+/// The names of all `*.txt` files in the directory, in numeric order where possible.
+fn find_examples(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut names = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? == "txt" {
+                Some(path.file_stem()?.to_str()?.to_string())
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    names.sort_by_key(|name| (name.parse::<u32>().unwrap_or(u32::MAX), name.clone()));
+
+    names
+}
+
+fn read_expected_answers(path: &Path) -> Answers {
+    match fs::read_to_string(path) {
+        Ok(content) => toml::from_str(&content)
+            .unwrap_or_else(|error| panic!("{} should be valid: {error}", path.display())),
+        Err(_) => Answers::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::{Answer, ParseError, SolveError};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            Ok(input.lines().filter_map(|line| line.parse().ok()).collect())
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(numbers.iter().sum::<u32>().into())
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(numbers.iter().product::<u32>().into())
+        }
+    }
+
+    fn examples_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for (file, content) in files {
+            fs::write(dir.join(file), content).unwrap();
+        }
+
+        dir
+    }
+
+    #[test]
+    fn check_examples_works() {
+        let dir = examples_dir(
+            "ok",
+            &[
+                ("1.txt", "2\n3\n"),
+                ("1.toml", "part-one = \"5\"\npart-two = \"6\"\n"),
+                ("2.txt", "4\n"),
+                ("2.toml", "part-two = \"4\"\n"),
+                ("10.txt", "1\n"),
+            ],
+        );
+
+        let examples = find_examples(&dir);
+        check_examples::<Sum>(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(vec!["1", "2", "10"], examples);
+    }
+
+    #[test]
+    fn check_examples_reports_every_mismatch() {
+        let dir = examples_dir(
+            "mismatch",
+            &[
+                ("1.txt", "2\n3\n"),
+                ("1.toml", "part-one = \"4\"\npart-two = \"7\"\n"),
+            ],
+        );
+
+        let result = std::panic::catch_unwind(|| check_examples::<Sum>(&dir));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("example 1, part one: expected 4, got 5"));
+        assert!(message.contains("example 1, part two: expected 7, got 6"));
+    }
+}
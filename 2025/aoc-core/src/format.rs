@@ -0,0 +1,11 @@
+use clap::ValueEnum;
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable text
+    #[default]
+    Text,
+    /// A single JSON document
+    Json,
+}
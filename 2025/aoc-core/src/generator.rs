@@ -0,0 +1,131 @@
+use crate::{Part, Rng, Solution};
+
+/// Generates a test that solves generated inputs of several sizes and seeds.
+#[macro_export]
+macro_rules! generator_tests {
+    ($solution:ty) => {
+        #[test]
+        fn generated_inputs_work() {
+            $crate::check_generated::<$solution>(&[1, 10, 100], 5);
+        }
+    };
+}
+
+/// Produces random but valid puzzle inputs of a day, for stress tests and benchmarks.
+pub trait Generator: Solution {
+    /// Generates an input that grows with `size`, e.g. the number of lines.
+    ///
+    /// `size` is at least 1. The same `rng` state always produces the same input.
+    fn generate(size: usize, rng: &mut Rng) -> String;
+}
+
+/// Generates an input with the given size and seed.
+pub fn generate<S: Generator>(size: usize, seed: u64) -> String {
+    S::generate(size, &mut Rng::new(seed))
+}
+
+/// Checks that the inputs generated for every size and the seeds `0..seeds` parse and solve,
+/// and panics with a summary of all failures.
+pub fn check_generated<S: Generator>(sizes: &[usize], seeds: u64) {
+    let mut failures = Vec::new();
+
+    for &size in sizes {
+        for seed in 0..seeds {
+            let input = generate::<S>(size, seed);
+
+            if input != generate::<S>(size, seed) {
+                failures.push(format!(
+                    "size {size}, seed {seed}: the input is not reproducible"
+                ));
+            }
+
+            let parsed = match S::parse(&input) {
+                Ok(parsed) => parsed,
+                Err(error) => {
+                    failures.push(format!(
+                        "size {size}, seed {seed}: parsing failed: {}",
+                        error.render(&input)
+                    ));
+                    continue;
+                }
+            };
+
+            for part in Part::ALL {
+                let result = match part {
+                    Part::One => S::part_one(&parsed),
+                    Part::Two => S::part_two(&parsed),
+                };
+
+                if let Err(error) = result {
+                    failures.push(format!(
+                        "size {size}, seed {seed}, part {part}: solving failed: {error}"
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, SolveError};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+
+        type Parsed = Vec<u8>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| ParseError::at(input, line, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            parsed
+                .iter()
+                .try_fold(0_u8, |sum, number| sum.checked_add(*number))
+                .map(Answer::from)
+                .ok_or(SolveError::Overflow)
+        }
+
+        fn part_two(parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(parsed.len().into())
+        }
+    }
+
+    impl Generator for Sum {
+        fn generate(size: usize, rng: &mut Rng) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.range(0..=99)))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn generate_is_reproducible() {
+        assert_eq!(generate::<Sum>(10, 3), generate::<Sum>(10, 3));
+        assert_ne!(generate::<Sum>(10, 3), generate::<Sum>(10, 4));
+        assert_eq!(10, generate::<Sum>(10, 3).lines().count());
+    }
+
+    #[test]
+    fn check_generated_works() {
+        check_generated::<Sum>(&[1, 2], 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "solving failed: the answer overflowed its integer type")]
+    fn check_generated_reports_failures() {
+        check_generated::<Sum>(&[100], 1);
+    }
+}
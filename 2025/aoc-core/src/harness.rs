@@ -0,0 +1,162 @@
+use std::{num::NonZeroUsize, panic, path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+
+use crate::{
+    BenchConfig, BenchReport, DayOutcome, DayReport, DefaultInput, ErrorKind, Format, Generator,
+    Part, bench, format_bytes, generate, read_input, solve,
+};
+
+/// The command line every day binary understands.
+#[derive(Parser)]
+struct Args {
+    /// Only solve the given part (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Read the puzzle input from this file or from stdin for `-` instead of the day's input.txt
+    #[arg(long, value_name = "PATH")]
+    input: Option<PathBuf>,
+
+    /// Time parsing and each part instead of printing the answers
+    #[arg(long)]
+    bench: bool,
+
+    /// Number of untimed runs of each phase before sampling
+    #[arg(long, default_value_t = 1, requires = "bench")]
+    warmup: u32,
+
+    /// Number of timed runs of each phase
+    #[arg(long, default_value_t = 10, requires = "bench", value_parser = clap::value_parser!(u32).range(1..))]
+    samples: u32,
+
+    /// Print a random input of this size, e.g. the number of lines, instead of solving
+    #[arg(long, value_name = "SIZE", conflicts_with_all = ["part", "input", "bench"])]
+    generate: Option<NonZeroUsize>,
+
+    /// The seed of the generated input
+    #[arg(long, default_value_t = 0, requires = "generate")]
+    seed: u64,
+
+    /// The output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+impl Args {
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+/// Reads the input selected on the command line, parses it
+/// and prints the answers of the selected parts or their timings.
+pub fn run<S: Generator>(default_input: DefaultInput) -> ExitCode {
+    let args = Args::parse();
+
+    if let Some(size) = args.generate {
+        print!("{}", generate::<S>(size.get(), args.seed));
+        return ExitCode::SUCCESS;
+    }
+
+    if args.format == Format::Json {
+        // panics are reported as error objects on stdout
+        panic::set_hook(Box::new(|_| {}));
+    }
+
+    let input = match read_input(args.input.as_deref(), default_input) {
+        Ok(input) => input,
+        Err(error) => {
+            let report = DayReport::error(S::DAY, ErrorKind::Input, error.to_string());
+            return print_report(&report, args.format);
+        }
+    };
+
+    if args.bench {
+        let config = BenchConfig {
+            warmup: args.warmup,
+            samples: args.samples,
+        };
+
+        return match bench::<S>(&input, &args.parts(), &config) {
+            Ok(report) => {
+                print_bench_report(&report, &config, args.format);
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                let report = DayReport::error(S::DAY, ErrorKind::Parse, error.render(&input));
+                print_report(&report, args.format)
+            }
+        };
+    }
+
+    print_report(&solve::<S>(&input, &args.parts()), args.format)
+}
+
+fn print_report(report: &DayReport, format: Format) -> ExitCode {
+    match format {
+        Format::Text => match &report.outcome {
+            DayOutcome::Parts(results) => {
+                println!("answers:");
+
+                for result in results {
+                    if let Some(part) = Part::from_number(result.part) {
+                        println!(" - part {part}: {}", result.answer);
+                    }
+                }
+
+                if !report.allocations.is_empty() {
+                    println!("allocations:");
+                }
+
+                for allocations in report.allocations.iter() {
+                    println!(
+                        " - {}: {} allocations, {}, peak heap {}",
+                        allocations.phase,
+                        allocations.allocations,
+                        format_bytes(allocations.bytes),
+                        format_bytes(allocations.peak_heap_bytes)
+                    );
+                }
+            }
+            DayOutcome::Error(error) => eprintln!("error: {error}"),
+        },
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(report).expect("day report should be serializable")
+        ),
+    }
+
+    match report.outcome {
+        DayOutcome::Parts(_) => ExitCode::SUCCESS,
+        DayOutcome::Error(_) => ExitCode::FAILURE,
+    }
+}
+
+fn print_bench_report(report: &BenchReport, config: &BenchConfig, format: Format) {
+    match format {
+        Format::Text => {
+            println!(
+                "timings ({} warmup, {} samples):",
+                config.warmup, config.samples
+            );
+
+            for timings in report.phases.iter() {
+                println!(
+                    " - {}: min {:.2?}, median {:.2?}, max {:.2?}",
+                    timings.phase,
+                    timings.min(),
+                    timings.median(),
+                    timings.max()
+                );
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(report).expect("bench report should be serializable")
+        ),
+    }
+}
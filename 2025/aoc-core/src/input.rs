@@ -0,0 +1,77 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::Path,
+};
+
+/// Where a day gets its input from when no `--input` is given on the command line.
+///
+/// Use [`default_input!`](crate::default_input) to create it for the calling crate.
+pub enum DefaultInput {
+    Embedded(&'static str),
+    File(&'static str),
+}
+
+/// The `input.txt` next to the `Cargo.toml` of the calling crate.
+///
+/// It is read at runtime unless the calling crate enables its `embed-input` feature,
+/// in which case it is embedded into the binary at compile time.
+#[macro_export]
+macro_rules! default_input {
+    () => {{
+        #[cfg(feature = "embed-input")]
+        let input = $crate::DefaultInput::Embedded(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/input.txt"
+        )));
+
+        #[cfg(not(feature = "embed-input"))]
+        let input = $crate::DefaultInput::File(concat!(env!("CARGO_MANIFEST_DIR"), "/input.txt"));
+
+        input
+    }};
+}
+
+/// Reads the input from the given path, from stdin for `-` or falls back to the default input.
+pub fn read_input(path: Option<&Path>, default: DefaultInput) -> io::Result<String> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => match default {
+            DefaultInput::Embedded(input) => Ok(input.to_string()),
+            DefaultInput::File(path) => fs::read_to_string(path),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_default_input_is_used_without_path() {
+        let input = read_input(None, DefaultInput::Embedded("1\n2")).unwrap();
+        assert_eq!("1\n2", input);
+    }
+
+    #[test]
+    fn file_default_input_is_read() {
+        let input = read_input(
+            None,
+            DefaultInput::File(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")),
+        )
+        .unwrap();
+        assert!(input.contains("name = \"aoc-core\""));
+    }
+
+    #[test]
+    fn given_path_takes_precedence() {
+        let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"));
+        let input = read_input(Some(path), DefaultInput::Embedded("")).unwrap();
+        assert!(input.contains("name = \"aoc-core\""));
+    }
+}
@@ -0,0 +1,31 @@
+mod allocations;
+mod answer;
+mod answers;
+mod bench;
+mod examples;
+mod format;
+mod generator;
+mod harness;
+mod input;
+mod parse_error;
+mod part;
+mod report;
+mod rng;
+mod solution;
+mod solve_error;
+
+pub use allocations::*;
+pub use answer::*;
+pub use answers::*;
+pub use bench::*;
+pub use examples::*;
+pub use format::*;
+pub use generator::*;
+pub use harness::*;
+pub use input::*;
+pub use parse_error::*;
+pub use part::*;
+pub use report::*;
+pub use rng::*;
+pub use solution::*;
+pub use solve_error::*;
@@ -0,0 +1,124 @@
+use std::fmt::Display;
+
+/// Why and where parsing the puzzle input failed.
+///
+/// Lines and columns start at 1, columns are counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text, empty if something is missing at the end of a line.
+    pub text: String,
+    /// What was expected instead, e.g. "expected a number".
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error pointing at `text`, which has to be a slice of `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `text` is not a slice of `input`.
+    pub fn at(input: &str, text: &str, reason: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| offset + text.len() <= input.len())
+            .expect("text should be a slice of the input");
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            text,
+            reason,
+        )
+    }
+
+    /// Renders the error followed by the offending line of `input` with a caret under the problem.
+    pub fn render(&self, input: &str) -> String {
+        let line = input
+            .lines()
+            .nth(self.line.saturating_sub(1))
+            .unwrap_or_default();
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let caret = " ".repeat(self.column.saturating_sub(1))
+            + &"^".repeat(
+                self.text
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .chars()
+                    .count()
+                    .max(1),
+            );
+
+        format!("{self}\n{gutter} |\n{number} | {line}\n{gutter} | {caret}")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}, ",
+            self.line, self.column, self.reason
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found `{}`", self.text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "L68\nR30\nX48\n";
+
+    #[test]
+    fn at_computes_line_and_column() {
+        let error = ParseError::at(INPUT, &INPUT[9..10], "expected `L` or `R`");
+        assert_eq!(ParseError::new(3, 2, "4", "expected `L` or `R`"), error);
+
+        let error = ParseError::at(INPUT, &INPUT[..0], "expected a rotation");
+        assert_eq!((1, 1), (error.line, error.column));
+    }
+
+    #[test]
+    #[should_panic(expected = "text should be a slice of the input")]
+    fn at_rejects_foreign_text() {
+        ParseError::at(INPUT, &String::from("X48"), "expected `L` or `R`");
+    }
+
+    #[test]
+    fn render_works() {
+        let error = ParseError::at(INPUT, &INPUT[8..9], "expected `L` or `R`");
+        let expected = "line 3, column 1: expected `L` or `R`, found `X`\n  |\n3 | X48\n  | ^";
+        assert_eq!(expected, error.render(INPUT));
+
+        let error = ParseError::new(2, 4, "", "expected another digit");
+        let expected =
+            "line 2, column 4: expected another digit, found nothing\n  |\n2 | R30\n  |    ^";
+        assert_eq!(expected, error.render(INPUT));
+    }
+}
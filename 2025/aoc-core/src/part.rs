@@ -0,0 +1,78 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "one"),
+            Part::Two => write!(f, "two"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidPart(String);
+
+impl Display for InvalidPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a part, expected 1 or 2", self.0)
+    }
+}
+
+impl Error for InvalidPart {}
+
+impl FromStr for Part {
+    type Err = InvalidPart;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            _ => Err(InvalidPart(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_parsing_works() {
+        assert_eq!(Part::One, "1".parse().unwrap());
+        assert_eq!(Part::Two, "two".parse().unwrap());
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn part_number_round_trips() {
+        for part in Part::ALL {
+            assert_eq!(part, part.number().to_string().parse().unwrap());
+            assert_eq!(Some(part), Part::from_number(part.number()));
+        }
+    }
+}
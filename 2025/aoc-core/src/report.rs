@@ -0,0 +1,271 @@
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Answers, Part, Phase, PhaseAllocations, Solution, measure_allocations};
+
+/// The answer of a single part and how long computing it took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub duration_ns: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ErrorKind {
+    /// The puzzle input could not be read.
+    Input,
+    /// The puzzle input could not be parsed.
+    Parse,
+    /// Solving a part failed or panicked.
+    Solve,
+    /// The day exited without reporting anything.
+    Crash,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::Input => write!(f, "reading the puzzle input failed"),
+            ErrorKind::Parse => write!(f, "parsing the puzzle input failed"),
+            ErrorKind::Solve => write!(f, "solving failed"),
+            ErrorKind::Crash => write!(f, "the day crashed"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+impl Display for ErrorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DayOutcome {
+    Parts(Vec<PartResult>),
+    Error(ErrorReport),
+}
+
+/// Everything a day reports about a run, as printed with `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    #[serde(flatten)]
+    pub outcome: DayOutcome,
+    /// Only reported by days built with the `alloc-stats` feature.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allocations: Vec<PhaseAllocations>,
+}
+
+impl DayReport {
+    pub fn error(day: u8, kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            day,
+            outcome: DayOutcome::Error(ErrorReport {
+                kind,
+                message: message.into(),
+            }),
+            allocations: Vec::new(),
+        }
+    }
+
+    pub fn answers(&self) -> Answers {
+        let mut answers = Answers::default();
+
+        if let DayOutcome::Parts(results) = &self.outcome {
+            for result in results {
+                match result.part {
+                    1 => answers.part_one = Some(result.answer.clone()),
+                    2 => answers.part_two = Some(result.answer.clone()),
+                    _ => {}
+                }
+            }
+        }
+
+        answers
+    }
+}
+
+/// Parses the input and solves the given parts, turning errors and panics into error reports.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> DayReport {
+    let (parsed, parse_allocations) =
+        measure_allocations(Phase::Parse, || panic::catch_unwind(|| S::parse(input)));
+
+    let parsed = match parsed {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(error)) => return DayReport::error(S::DAY, ErrorKind::Parse, error.render(input)),
+        Err(panic) => return DayReport::error(S::DAY, ErrorKind::Parse, panic_message(panic)),
+    };
+
+    let mut results = Vec::new();
+    let mut allocations = Vec::from_iter(parse_allocations);
+
+    for part in parts {
+        let start = Instant::now();
+
+        let (answer, part_allocations) = measure_allocations((*part).into(), || {
+            panic::catch_unwind(AssertUnwindSafe(|| match part {
+                Part::One => S::part_one(&parsed),
+                Part::Two => S::part_two(&parsed),
+            }))
+        });
+
+        let duration_ns = start.elapsed().as_nanos() as u64;
+        allocations.extend(part_allocations);
+
+        match answer {
+            Ok(Ok(answer)) => results.push(PartResult {
+                part: part.number(),
+                answer: answer.to_string(),
+                duration_ns,
+            }),
+            Ok(Err(error)) => {
+                return DayReport::error(S::DAY, ErrorKind::Solve, format!("part {part}: {error}"));
+            }
+            Err(panic) => {
+                return DayReport::error(
+                    S::DAY,
+                    ErrorKind::Solve,
+                    format!("part {part}: {}", panic_message(panic)),
+                );
+            }
+        }
+    }
+
+    DayReport {
+        day: S::DAY,
+        outcome: DayOutcome::Parts(results),
+        allocations,
+    }
+}
+
+/// The message a panic was raised with, as caught by [`std::panic::catch_unwind`].
+pub fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError, SolveError};
+
+    struct Strict;
+
+    impl Solution for Strict {
+        const DAY: u8 = 7;
+
+        type Parsed = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse()
+                        .map_err(|_| ParseError::at(input, line, "expected a number"))
+                })
+                .collect()
+        }
+
+        fn part_one(numbers: &Self::Parsed) -> Result<Answer, SolveError> {
+            numbers
+                .iter()
+                .try_fold(0_u32, |sum, number| sum.checked_add(*number))
+                .map(Answer::from)
+                .ok_or(SolveError::Overflow)
+        }
+
+        fn part_two(numbers: &Self::Parsed) -> Result<Answer, SolveError> {
+            Ok(numbers[3].into())
+        }
+    }
+
+    #[test]
+    fn solve_works() {
+        let report = solve::<Strict>("1\n2", &[Part::One]);
+        let expected = Answers {
+            part_one: Some("3".to_string()),
+            part_two: None,
+        };
+        assert_eq!(7, report.day);
+        assert_eq!(expected, report.answers());
+    }
+
+    #[test]
+    fn solve_reports_parse_errors() {
+        let report = solve::<Strict>("1\nx", &Part::ALL);
+        let expected = DayReport::error(
+            7,
+            ErrorKind::Parse,
+            "line 2, column 1: expected a number, found `x`\n  |\n2 | x\n  | ^",
+        );
+        assert_eq!(expected, report);
+    }
+
+    #[test]
+    fn solve_reports_solve_errors() {
+        let report = solve::<Strict>("4294967295\n1", &Part::ALL);
+        let expected = DayReport::error(
+            7,
+            ErrorKind::Solve,
+            "part one: the answer overflowed its integer type",
+        );
+        assert_eq!(expected, report);
+    }
+
+    #[test]
+    fn solve_reports_panics() {
+        let report = solve::<Strict>("1\n2", &Part::ALL);
+        let DayOutcome::Error(error) = report.outcome else {
+            panic!("part two should fail");
+        };
+        assert_eq!(ErrorKind::Solve, error.kind);
+        assert!(error.message.starts_with("part two: index out of bounds"));
+    }
+
+    #[test]
+    fn day_report_serialization_works() {
+        let report = DayReport {
+            day: 4,
+            outcome: DayOutcome::Parts(vec![PartResult {
+                part: 1,
+                answer: "13".to_string(),
+                duration_ns: 1200,
+            }]),
+            allocations: Vec::new(),
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        let expected = r#"{"day":4,"parts":[{"part":1,"answer":"13","duration_ns":1200}]}"#;
+        assert_eq!(expected, json);
+        assert_eq!(report, serde_json::from_str(&json).unwrap());
+
+        let error = DayReport::error(
+            4,
+            ErrorKind::Parse,
+            "line 1, column 1: expected a grid, found nothing",
+        );
+        let json = serde_json::to_string(&error).unwrap();
+        let expected = r#"{"day":4,"error":{"kind":"parse","message":"line 1, column 1: expected a grid, found nothing"}}"#;
+        assert_eq!(expected, json);
+        assert_eq!(error, serde_json::from_str(&json).unwrap());
+    }
+}
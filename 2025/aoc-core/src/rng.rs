@@ -0,0 +1,105 @@
+use std::ops::RangeInclusive;
+
+/// A small, seedable random number generator (SplitMix64) for generating puzzle inputs.
+///
+/// The same seed always produces the same numbers, on every platform.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound should be positive");
+
+        // the high half of the product is close enough to uniform for puzzle inputs
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    /// A number in the range, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "range should not be empty");
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // the 53 high bits are a uniform float in 0..1
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < probability
+    }
+
+    /// A random item of the slice, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_numbers() {
+        let first = (0..5).map(|_| Rng::new(7).next_u64()).collect::<Vec<_>>();
+        assert!(first.iter().all(|number| *number == first[0]));
+
+        let mut rng = Rng::new(7);
+        let mut other = Rng::new(8);
+        assert_ne!(rng.next_u64(), other.next_u64());
+    }
+
+    #[test]
+    fn next_u64_matches_splitmix64() {
+        // the first outputs of the reference implementation for seed 1234567
+        let mut rng = Rng::new(1234567);
+        assert_eq!(6457827717110365317, rng.next_u64());
+        assert_eq!(3203168211198807973, rng.next_u64());
+    }
+
+    #[test]
+    fn range_stays_in_bounds() {
+        let mut rng = Rng::new(42);
+
+        for _ in 0..1000 {
+            assert!((3..=9).contains(&rng.range(3..=9)));
+            assert!(rng.below(2) < 2);
+        }
+
+        assert_eq!(5, rng.range(5..=5));
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn chance_works() {
+        let mut rng = Rng::new(42);
+
+        assert!((0..100).all(|_| rng.chance(1.0)));
+        assert!((0..100).all(|_| !rng.chance(0.0)));
+
+        let hits = (0..10_000).filter(|_| rng.chance(0.25)).count();
+        assert!((2000..3000).contains(&hits));
+    }
+}
@@ -0,0 +1,16 @@
+use crate::{Answer, ParseError, SolveError};
+
+/// The common shape of every day: parse the puzzle input once and
+/// compute both parts from the parsed representation.
+pub trait Solution {
+    /// The day of the puzzle, 1 to 25.
+    const DAY: u8;
+
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part_one(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+
+    fn part_two(parsed: &Self::Parsed) -> Result<Answer, SolveError>;
+}
@@ -0,0 +1,19 @@
+use std::fmt::Display;
+
+/// Why a part could not be solved for an input that parsed fine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// An intermediate result or the answer does not fit into its integer type.
+    Overflow,
+    /// The input is well-formed but has no answer, e.g. it is too small.
+    Unsolvable(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Overflow => write!(f, "the answer overflowed its integer type"),
+            SolveError::Unsolvable(reason) => write!(f, "{reason}"),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt::Display;
+
+/// The position of a cell: `x` is the column and `y` the row, both counted from the top left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Coordinate {
+    pub x: usize,
+    pub y: usize,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CoordinateNotInGrid {
+    pub coord: Coordinate,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Display for CoordinateNotInGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "coordinate ({}, {}) is outside of the {}x{} grid",
+            self.coord.x, self.coord.y, self.width, self.height
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{Boundary, Coordinate, Grid, Point};
+
+/// A compass direction on a grid, where north is up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from north.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// All 8 directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The offset of one step in this direction.
+    pub fn vector(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::NorthEast => Point::new(1, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(0, 1),
+            Direction::SouthWest => Point::new(-1, 1),
+            Direction::West => Point::new(-1, 0),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        !Self::CARDINAL.contains(&self)
+    }
+
+    /// Rotates clockwise by `eighths` of a full turn, counterclockwise if negative.
+    pub fn rotate(self, eighths: i32) -> Self {
+        // `rem_euclid(8)` leaves at most 7, so the sum is at most 14
+        Self::ALL[(self as i32 + eighths.rem_euclid(8)).rem_euclid(8) as usize]
+    }
+
+    /// Rotates by 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Rotates by 90° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+}
+
+impl<T> Grid<T> {
+    /// The coordinate one step away in the direction, or `None` if the step leaves the grid.
+    pub fn step(&self, coord: &Coordinate, direction: Direction) -> Option<Coordinate> {
+        let vector = direction.vector();
+        self.offset(
+            coord,
+            (vector.x as isize, vector.y as isize),
+            Boundary::Clip,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_works() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::SouthWest, Direction::NorthEast.opposite());
+        assert_eq!(Direction::NorthWest, Direction::North.rotate(-1));
+        assert_eq!(Direction::NorthEast, Direction::North.rotate(17));
+        assert_eq!(Direction::SouthEast, Direction::South.rotate(i32::MAX));
+        assert_eq!(Direction::South, Direction::South.rotate(i32::MIN));
+        assert_eq!(Direction::North, Direction::NorthWest.rotate(i32::MAX - 6));
+
+        for direction in Direction::ALL {
+            assert_eq!(direction, direction.turn_right().turn_left());
+            assert_eq!(-direction.vector(), direction.opposite().vector());
+        }
+    }
+
+    #[test]
+    fn vectors_match_the_order() {
+        for (index, direction) in Direction::ALL.iter().enumerate() {
+            let next = Direction::ALL[(index + 1) % 8];
+            assert_eq!(next, direction.rotate(1));
+            assert_eq!(1, direction.vector().chebyshev_distance(&Point::ORIGIN));
+        }
+
+        let diagonal = Direction::ALL
+            .iter()
+            .filter(|direction| direction.is_diagonal())
+            .count();
+        assert_eq!(4, diagonal);
+    }
+
+    #[test]
+    fn step_on_grid_works() {
+        let grid = Grid::filled(3, 2, ());
+        let corner = Coordinate { x: 0, y: 1 };
+
+        assert_eq!(
+            Some(Coordinate { x: 1, y: 0 }),
+            grid.step(&corner, Direction::NorthEast)
+        );
+        assert_eq!(None, grid.step(&corner, Direction::West));
+        assert_eq!(None, grid.step(&corner, Direction::South));
+    }
+}
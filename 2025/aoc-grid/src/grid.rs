@@ -0,0 +1,261 @@
+use std::fmt::Display;
+
+use aoc_core::ParseError;
+
+use crate::{Coordinate, CoordinateNotInGrid};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells, row by row, or returns `None` if their number is not `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if width.checked_mul(height)? != cells.len() {
+            return None;
+        }
+
+        Some(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn filled(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![cell; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses one cell per character and one row per line, with `cell` mapping every character.
+    ///
+    /// An error of `cell` becomes the reason of the [`ParseError`], e.g. "expected `#` or `.`".
+    pub fn parse_with<E: Display>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+
+        let width = lines.first().map_or(0, |line| line.chars().count());
+
+        if width == 0 {
+            let start = lines.first().unwrap_or(&input);
+            return Err(ParseError::at(input, start, "expected a grid"));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+
+        for line in lines.iter() {
+            let mut line_width = 0;
+
+            for (index, char) in line.char_indices() {
+                if line_width == width {
+                    let rest = &line[index..];
+                    return Err(ParseError::at(
+                        input,
+                        rest,
+                        format!("expected {width} cells"),
+                    ));
+                }
+
+                let text = &line[index..index + char.len_utf8()];
+                cells.push(
+                    cell(char).map_err(|error| ParseError::at(input, text, error.to_string()))?,
+                );
+                line_width += 1;
+            }
+
+            if line_width < width {
+                let end = &line[line.len()..];
+                return Err(ParseError::at(
+                    input,
+                    end,
+                    format!("expected {width} cells"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            cells,
+            width,
+            height: lines.len(),
+        })
+    }
+
+    /// The cells row by row.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn iter<'a>(&'a self) -> GridIterator<'a, T> {
+        GridIterator {
+            index: 0,
+            grid: self,
+        }
+    }
+
+    pub fn contains(&self, coord: &Coordinate) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn at(&self, coord: &Coordinate) -> Option<&T> {
+        self.index(coord).map(|index| &self.cells[index])
+    }
+
+    pub fn at_mut(&mut self, coord: &Coordinate) -> Option<&mut T> {
+        self.index(coord).map(|index| &mut self.cells[index])
+    }
+
+    pub fn set(&mut self, coord: &Coordinate, cell: T) -> Result<(), CoordinateNotInGrid> {
+        let Some(index) = self.index(coord) else {
+            return Err(CoordinateNotInGrid {
+                coord: *coord,
+                width: self.width,
+                height: self.height,
+            });
+        };
+
+        self.cells[index] = cell;
+
+        Ok(())
+    }
+
+    fn index(&self, coord: &Coordinate) -> Option<usize> {
+        self.contains(coord).then(|| coord.x + self.width * coord.y)
+    }
+}
+
+impl<T> TryFrom<&str> for Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: Display,
+{
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::parse_with(value, T::try_from)
+    }
+}
+
+pub struct GridIterator<'a, T> {
+    index: usize,
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Iterator for GridIterator<'a, T> {
+    type Item = (Coordinate, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cell = self.grid.cells.get(self.index)?;
+        let coord = Coordinate {
+            x: self.index % self.grid.width,
+            y: self.index / self.grid.width,
+        };
+        self.index += 1;
+        Some((coord, cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err("expected `#` or `.`"),
+            }
+        }
+    }
+
+    #[test]
+    fn try_from_works() {
+        use Tile::*;
+
+        let grid = Grid::<Tile>::try_from("..#\n#..\n").unwrap();
+        assert_eq!((3, 2), (grid.width, grid.height));
+        assert_eq!(&[Open, Open, Wall, Wall, Open, Open], grid.cells());
+        assert_eq!(Some(&Wall), grid.at(&Coordinate { x: 0, y: 1 }));
+        assert_eq!(None, grid.at(&Coordinate { x: 3, y: 0 }));
+    }
+
+    #[test]
+    fn parse_with_works() {
+        let grid = Grid::parse_with("12\n34\n", |char| {
+            char.to_digit(10).ok_or("expected a digit")
+        })
+        .unwrap();
+        assert_eq!(&[1, 2, 3, 4], grid.cells());
+    }
+
+    #[test]
+    fn parse_reports_invalid_grids() {
+        let error = Grid::<Tile>::try_from("").unwrap_err();
+        assert_eq!(ParseError::new(1, 1, "", "expected a grid"), error);
+
+        let error = Grid::<Tile>::try_from("\n..#\n").unwrap_err();
+        assert_eq!(ParseError::new(1, 1, "", "expected a grid"), error);
+
+        let error = Grid::<Tile>::try_from("..#\n.x.\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 2, "x", "expected `#` or `.`"), error);
+
+        let error = Grid::<Tile>::try_from("..#\n..\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 3, "", "expected 3 cells"), error);
+
+        let error = Grid::<Tile>::try_from("..#\n..##.\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 4, "#.", "expected 3 cells"), error);
+    }
+
+    #[test]
+    fn new_checks_the_number_of_cells() {
+        assert_eq!(None, Grid::new(2, 2, vec![1, 2, 3]));
+        assert_eq!(Some(Grid::filled(2, 1, 0)), Grid::new(2, 1, vec![0, 0]));
+    }
+
+    #[test]
+    fn set_outside_of_grid_fails() {
+        let mut grid = Grid::filled(3, 2, Tile::Open);
+        let error = grid
+            .set(&Coordinate { x: 3, y: 0 }, Tile::Wall)
+            .unwrap_err();
+        assert_eq!(
+            "coordinate (3, 0) is outside of the 3x2 grid",
+            error.to_string()
+        );
+
+        grid.set(&Coordinate { x: 2, y: 1 }, Tile::Wall).unwrap();
+        *grid.at_mut(&Coordinate { x: 0, y: 0 }).unwrap() = Tile::Wall;
+        assert_eq!(
+            2,
+            grid.iter().filter(|(_, tile)| **tile == Tile::Wall).count()
+        );
+    }
+
+    #[test]
+    fn iter_works() {
+        let grid = Grid::new(2, 2, vec!['a', 'b', 'c', 'd']).unwrap();
+        let cells = grid.iter().collect::<Vec<_>>();
+        assert_eq!((Coordinate { x: 1, y: 1 }, &'d'), cells[3]);
+        assert_eq!((Coordinate { x: 0, y: 1 }, &'c'), cells[2]);
+    }
+}
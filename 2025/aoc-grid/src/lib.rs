@@ -0,0 +1,14 @@
+mod coordinate;
+mod direction;
+mod grid;
+mod neighbors;
+mod pathfinding;
+mod point;
+mod render;
+
+pub use coordinate::*;
+pub use direction::*;
+pub use grid::*;
+pub use neighbors::*;
+pub use pathfinding::*;
+pub use point::*;
@@ -0,0 +1,173 @@
+use crate::{Coordinate, Grid};
+
+/// The offsets of the cells above, right of, below and left of a cell.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The offsets of the four diagonal cells, clockwise from the top left.
+pub const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+
+/// The offsets of the eight cells around a cell, clockwise from the top left.
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// What happens to offsets that leave the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// Cells outside of the grid are left out.
+    Clip,
+    /// The grid is a torus: leaving it on one side enters it on the opposite side.
+    Wrap,
+}
+
+impl<T> Grid<T> {
+    /// The 4 orthogonal neighbors of a cell that are inside of the grid.
+    pub fn neighbors4(&self, coord: &Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.stencil(coord, &ORTHOGONAL, Boundary::Clip)
+    }
+
+    /// The 8 orthogonal and diagonal neighbors of a cell that are inside of the grid.
+    pub fn neighbors8(&self, coord: &Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.stencil(coord, &SURROUNDING, Boundary::Clip)
+    }
+
+    /// The cells at the given `(dx, dy)` offsets from a cell, in the order of the offsets.
+    ///
+    /// With [`Boundary::Wrap`] a small grid can yield the same cell more than once.
+    pub fn stencil<'a>(
+        &'a self,
+        coord: &Coordinate,
+        offsets: &'a [(isize, isize)],
+        boundary: Boundary,
+    ) -> impl Iterator<Item = (Coordinate, &'a T)> {
+        let coord = *coord;
+
+        offsets.iter().filter_map(move |offset| {
+            let neighbor = self.offset(&coord, *offset, boundary)?;
+            self.at(&neighbor).map(|cell| (neighbor, cell))
+        })
+    }
+
+    /// The coordinate `(dx, dy)` away from a cell, or `None` if it is outside of the grid.
+    pub fn offset(
+        &self,
+        coord: &Coordinate,
+        (dx, dy): (isize, isize),
+        boundary: Boundary,
+    ) -> Option<Coordinate> {
+        Some(Coordinate {
+            x: shift(coord.x, dx, self.width, boundary)?,
+            y: shift(coord.y, dy, self.height, boundary)?,
+        })
+    }
+}
+
+fn shift(value: usize, delta: isize, len: usize, boundary: Boundary) -> Option<usize> {
+    match boundary {
+        Boundary::Clip => value.checked_add_signed(delta).filter(|value| *value < len),
+        Boundary::Wrap if len == 0 => None,
+        Boundary::Wrap => {
+            // i128 holds every usize and isize, so the sum cannot overflow
+            let shifted = (value % len) as i128 + delta as i128;
+            Some(shifted.rem_euclid(len as i128) as usize)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<u8> {
+        // 0 1 2
+        // 3 4 5
+        Grid::new(3, 2, (0..6).collect()).unwrap()
+    }
+
+    fn cells<'a>(neighbors: impl Iterator<Item = (Coordinate, &'a u8)>) -> Vec<u8> {
+        neighbors.map(|(_, cell)| *cell).collect()
+    }
+
+    #[test]
+    fn neighbors4_works() {
+        let grid = grid();
+        assert_eq!(
+            vec![2, 4, 0],
+            cells(grid.neighbors4(&Coordinate { x: 1, y: 0 }))
+        );
+        assert_eq!(
+            vec![1, 3],
+            cells(grid.neighbors4(&Coordinate { x: 0, y: 0 }))
+        );
+    }
+
+    #[test]
+    fn neighbors8_works() {
+        let grid = grid();
+        assert_eq!(
+            vec![1, 4, 3],
+            cells(grid.neighbors8(&Coordinate { x: 0, y: 0 }))
+        );
+        assert_eq!(
+            vec![0, 1, 2, 5, 3],
+            cells(grid.neighbors8(&Coordinate { x: 1, y: 1 }))
+        );
+        assert_eq!(
+            (Coordinate { x: 2, y: 1 }, &5),
+            grid.neighbors8(&Coordinate { x: 2, y: 0 }).next().unwrap()
+        );
+    }
+
+    #[test]
+    fn stencil_works() {
+        let grid = grid();
+        let knight = [(2, 1), (-2, 1), (1, 2)];
+        assert_eq!(
+            vec![5],
+            cells(grid.stencil(&Coordinate { x: 0, y: 0 }, &knight, Boundary::Clip))
+        );
+        assert_eq!(
+            vec![5, 3],
+            cells(grid.stencil(&Coordinate { x: 1, y: 0 }, &DIAGONAL, Boundary::Clip))
+        );
+    }
+
+    #[test]
+    fn wrapping_stencil_works() {
+        let grid = grid();
+        let origin = Coordinate { x: 0, y: 0 };
+        assert_eq!(
+            vec![3, 1, 3, 2],
+            cells(grid.stencil(&origin, &ORTHOGONAL, Boundary::Wrap))
+        );
+        assert_eq!(
+            Some(Coordinate { x: 2, y: 1 }),
+            grid.offset(&origin, (-4, 7), Boundary::Wrap)
+        );
+        assert_eq!(
+            Some(Coordinate { x: 1, y: 1 }),
+            grid.offset(&origin, (isize::MIN, isize::MAX), Boundary::Wrap)
+        );
+    }
+
+    #[test]
+    fn offset_outside_of_the_grid_is_none() {
+        let grid = grid();
+        let corner = Coordinate { x: 2, y: 1 };
+        assert_eq!(None, grid.offset(&corner, (1, 0), Boundary::Clip));
+        assert_eq!(None, grid.offset(&corner, (0, isize::MIN), Boundary::Clip));
+        assert_eq!(
+            None,
+            Grid::<u8>::new(0, 0, vec![])
+                .unwrap()
+                .offset(&corner, (0, 0), Boundary::Wrap)
+        );
+    }
+}
@@ -0,0 +1,549 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{Coordinate, Grid};
+
+/// A shortest path and its cost, the nodes run from the start to the goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: u64,
+    pub nodes: Vec<N>,
+}
+
+/// Every shortest path from the start to the goals that are reachable with the lowest cost.
+#[derive(Debug)]
+pub struct ShortestPaths<N> {
+    pub cost: u64,
+    goals: Vec<usize>,
+    search: Search<N>,
+}
+
+/// Finds a path with the fewest steps from `start` to a goal, every step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&search.nodes[index]) {
+            return Some(search.path(index));
+        }
+
+        let cost = search.costs[index] + 1;
+
+        for neighbor in neighbors(&search.nodes[index]) {
+            let (neighbor, is_new) = search.visit(neighbor);
+
+            if is_new {
+                search.costs[neighbor] = cost;
+                search.parents[neighbor].push(index);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds a cheapest path from `start` to a goal, `neighbors` gives every next node with the cost of the step.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(start, neighbors, |_| 0, is_goal)
+}
+
+/// Finds a cheapest path like [`dijkstra`], but explores the nodes that `heuristic` rates closer to a goal first.
+///
+/// The heuristic has to be consistent: it never overestimates the cost of a step plus the estimate after it,
+/// and it is 0 for goals. The Manhattan distance is consistent for orthogonal steps that cost at least 1.
+pub fn astar<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> u64,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (search, goals) = search(start, neighbors, heuristic, is_goal, false)?;
+    Some(search.path(goals[0]))
+}
+
+/// Finds every cheapest path from `start` to the goals, e.g. to find every node on any cheapest path.
+///
+/// Every step has to cost more than 0, otherwise the paths could run in circles.
+pub fn dijkstra_all<N, I>(
+    start: N,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<ShortestPaths<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (search, goals) = search(start, neighbors, |_| 0, is_goal, true)?;
+
+    Some(ShortestPaths {
+        cost: search.costs[goals[0]],
+        goals,
+        search,
+    })
+}
+
+impl<N: Clone + Eq + Hash> ShortestPaths<N> {
+    /// Every shortest path, there can be exponentially many of them.
+    pub fn paths(&self) -> Vec<Path<N>> {
+        let mut paths = Vec::new();
+
+        for goal in self.goals.iter() {
+            // one frame per node of the current path, from the goal back towards the start,
+            // each with the position of the parent to follow next; a path can be as long as the search
+            let mut stack = vec![(*goal, 0)];
+
+            while let Some(&(index, next_parent)) = stack.last() {
+                let parents = &self.search.parents[index];
+
+                if parents.is_empty() {
+                    paths.push(Path {
+                        cost: self.cost,
+                        nodes: stack
+                            .iter()
+                            .rev()
+                            .map(|(index, _)| self.search.nodes[*index].clone())
+                            .collect(),
+                    });
+                }
+
+                match parents.get(next_parent) {
+                    Some(parent) => {
+                        let top = stack.len() - 1;
+                        stack[top].1 += 1;
+                        stack.push((*parent, 0));
+                    }
+                    None => {
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Every node on any shortest path.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut on_path = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(index) = stack.pop() {
+            if on_path.insert(index) {
+                stack.extend(self.search.parents[index].iter());
+            }
+        }
+
+        on_path
+            .into_iter()
+            .map(|index| self.search.nodes[index].clone())
+            .collect()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds a path with the fewest orthogonal steps between two cells, only entering cells that are `passable`.
+    pub fn shortest_path(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<Path<Coordinate>> {
+        self.at(&start)?;
+
+        bfs(
+            start,
+            |coord| {
+                self.neighbors4(coord)
+                    .filter(|(_, cell)| passable(cell))
+                    .map(|(neighbor, _)| neighbor)
+                    .collect::<Vec<_>>()
+            },
+            |coord| *coord == goal,
+        )
+    }
+
+    /// Finds a cheapest path of orthogonal steps between two cells,
+    /// `cost` gives the cost of entering a cell or `None` for cells that cannot be entered.
+    pub fn cheapest_path(
+        &self,
+        start: Coordinate,
+        goal: Coordinate,
+        cost: impl Fn(&T) -> Option<u64>,
+    ) -> Option<Path<Coordinate>> {
+        self.at(&start)?;
+
+        dijkstra(
+            start,
+            |coord| {
+                self.neighbors4(coord)
+                    .filter_map(|(neighbor, cell)| Some((neighbor, cost(cell)?)))
+                    .collect::<Vec<_>>()
+            },
+            |coord| *coord == goal,
+        )
+    }
+}
+
+/// The nodes found so far, with their lowest known cost and the nodes they are reached from at that cost.
+#[derive(Debug)]
+struct Search<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    costs: Vec<u64>,
+    parents: Vec<Vec<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new(start: N) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            costs: vec![0],
+            parents: vec![Vec::new()],
+        }
+    }
+
+    /// The index of the node and whether it was found just now.
+    fn visit(&mut self, node: N) -> (usize, bool) {
+        if let Some(index) = self.indices.get(&node) {
+            return (*index, false);
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.costs.push(u64::MAX);
+        self.parents.push(Vec::new());
+
+        (index, true)
+    }
+
+    /// The path to the node along the first parent of every node.
+    fn path(&self, goal: usize) -> Path<N> {
+        let mut nodes = vec![self.nodes[goal].clone()];
+        let mut index = goal;
+
+        while let Some(parent) = self.parents[index].first() {
+            nodes.push(self.nodes[*parent].clone());
+            index = *parent;
+        }
+
+        nodes.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            nodes,
+        }
+    }
+}
+
+/// Runs A* and returns the search with the goals reached at the lowest cost,
+/// only the first one unless `all` is set, in which case every node keeps all of its cheapest parents.
+fn search<N, I>(
+    start: N,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut is_goal: impl FnMut(&N) -> bool,
+    all: bool,
+) -> Option<(Search<N>, Vec<usize>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut search = Search::new(start);
+    let mut goals = Vec::new();
+
+    while let Some(Reverse((estimate, cost, index))) = queue.pop() {
+        // nothing after the first goal is cheaper, but other goals and paths can be as cheap
+        if goals
+            .first()
+            .is_some_and(|goal: &usize| estimate > search.costs[*goal])
+        {
+            break;
+        }
+
+        if cost > search.costs[index] {
+            continue;
+        }
+
+        if is_goal(&search.nodes[index]) {
+            goals.push(index);
+
+            if !all {
+                break;
+            }
+
+            continue;
+        }
+
+        for (neighbor, step_cost) in neighbors(&search.nodes[index]) {
+            // a path whose cost overflows is as good as no path
+            let Some(neighbor_cost) = cost.checked_add(step_cost) else {
+                continue;
+            };
+            let (neighbor, _) = search.visit(neighbor);
+
+            if neighbor_cost < search.costs[neighbor] {
+                search.costs[neighbor] = neighbor_cost;
+                search.parents[neighbor] = vec![index];
+
+                let estimate = neighbor_cost.saturating_add(heuristic(&search.nodes[neighbor]));
+                queue.push(Reverse((estimate, neighbor_cost, neighbor)));
+            } else if all
+                && neighbor_cost == search.costs[neighbor]
+                && neighbor != index
+                // a node listed twice by `neighbors` is still only one parent
+                && search.parents[neighbor].last() != Some(&index)
+            {
+                search.parents[neighbor].push(index);
+            }
+        }
+    }
+
+    (!goals.is_empty()).then_some((search, goals))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Boundary, Point};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+";
+
+    fn maze() -> Grid<char> {
+        Grid::parse_with(MAZE, Ok::<char, &str>).unwrap()
+    }
+
+    fn coord(x: usize, y: usize) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    #[test]
+    fn shortest_path_works() {
+        let path = maze()
+            .shortest_path(coord(0, 0), coord(6, 0), |cell| *cell == '.')
+            .unwrap();
+
+        assert_eq!(12, path.cost);
+        assert_eq!(13, path.nodes.len());
+        assert_eq!(Some(&coord(0, 0)), path.nodes.first());
+        assert_eq!(Some(&coord(6, 0)), path.nodes.last());
+
+        for step in path.nodes.windows(2) {
+            let from = Point::try_from(step[0]).unwrap();
+            let to = Point::try_from(step[1]).unwrap();
+            assert_eq!(1, from.manhattan_distance(&to));
+        }
+    }
+
+    #[test]
+    fn unreachable_goal_has_no_path() {
+        let maze = maze();
+        assert_eq!(
+            None,
+            maze.shortest_path(coord(0, 0), coord(2, 0), |cell| *cell == '.')
+        );
+        assert_eq!(
+            None,
+            maze.shortest_path(coord(9, 0), coord(0, 0), |cell| *cell == '.')
+        );
+    }
+
+    #[test]
+    fn start_is_a_path_to_itself() {
+        let path = maze()
+            .shortest_path(coord(1, 0), coord(1, 0), |_| true)
+            .unwrap();
+        assert_eq!(
+            Path {
+                cost: 0,
+                nodes: vec![coord(1, 0)]
+            },
+            path
+        );
+    }
+
+    #[test]
+    fn cheapest_path_works() {
+        // entering a digit costs the digit, the direct way through the 9 is more expensive
+        let grid =
+            Grid::parse_with("1911\n1111\n", |char| char.to_digit(10).ok_or("digit")).unwrap();
+        let path = grid
+            .cheapest_path(coord(0, 0), coord(2, 0), |cost| Some(u64::from(*cost)))
+            .unwrap();
+
+        assert_eq!(4, path.cost);
+        assert_eq!(
+            vec![
+                coord(0, 0),
+                coord(0, 1),
+                coord(1, 1),
+                coord(2, 1),
+                coord(2, 0)
+            ],
+            path.nodes
+        );
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let grid = Grid::parse_with("131673\n233222\n631678\n534211\n", |char| {
+            char.to_digit(10).ok_or("digit")
+        })
+        .unwrap();
+        let goal = coord(5, 3);
+        let neighbors = |coord: &Coordinate| {
+            grid.neighbors4(coord)
+                .map(|(neighbor, cost)| (neighbor, u64::from(*cost)))
+                .collect::<Vec<_>>()
+        };
+        let manhattan = |coord: &Coordinate| {
+            Point::try_from(*coord)
+                .unwrap()
+                .manhattan_distance(&Point::try_from(goal).unwrap())
+        };
+
+        let expected = dijkstra(coord(0, 0), neighbors, |coord| *coord == goal).unwrap();
+        let path = astar(coord(0, 0), neighbors, manhattan, |coord| *coord == goal).unwrap();
+
+        assert_eq!(expected.cost, path.cost);
+        assert_eq!(16, path.cost);
+    }
+
+    #[test]
+    fn dijkstra_all_finds_every_shortest_path() {
+        // every monotone path through a 3x3 grid is a shortest path to the opposite corner
+        let grid = Grid::filled(3, 3, ());
+        let neighbors = |coord: &Coordinate| {
+            grid.neighbors4(coord)
+                .map(|(neighbor, _)| (neighbor, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let goal = coord(2, 2);
+        let shortest = dijkstra_all(coord(0, 0), neighbors, |coord| *coord == goal).unwrap();
+        let paths = shortest.paths();
+
+        assert_eq!(4, shortest.cost);
+        assert_eq!(6, paths.len());
+        assert!(paths.iter().all(|path| path.nodes.len() == 5));
+        assert_eq!(9, shortest.nodes().len());
+
+        let paths = paths
+            .into_iter()
+            .map(|path| path.nodes)
+            .collect::<HashSet<_>>();
+        assert_eq!(6, paths.len());
+    }
+
+    #[test]
+    fn dijkstra_all_keeps_only_the_cheapest_paths() {
+        let maze = maze();
+        let neighbors = |coord: &Coordinate| {
+            maze.neighbors4(coord)
+                .filter(|(_, cell)| **cell == '.')
+                .map(|(neighbor, _)| (neighbor, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let goal = coord(6, 0);
+        let shortest = dijkstra_all(coord(0, 0), neighbors, |coord| *coord == goal).unwrap();
+
+        assert_eq!(12, shortest.cost);
+        assert!(
+            shortest
+                .paths()
+                .iter()
+                .all(|path| path.cost == 12 && path.nodes.len() == 13)
+        );
+        assert!(!shortest.nodes().contains(&coord(0, 3)));
+    }
+
+    #[test]
+    fn dijkstra_all_ignores_neighbors_listed_twice() {
+        // on a wrapping grid that is 2 cells wide, left and right are the same neighbor
+        let grid = Grid::filled(2, 1, ());
+        let neighbors = |coord: &Coordinate| {
+            grid.stencil(coord, &[(1, 0), (-1, 0)], Boundary::Wrap)
+                .map(|(neighbor, _)| (neighbor, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let goal = coord(1, 0);
+        let shortest = dijkstra_all(coord(0, 0), neighbors, |coord| *coord == goal).unwrap();
+
+        assert_eq!(
+            vec![Path {
+                cost: 1,
+                nodes: vec![coord(0, 0), coord(1, 0)]
+            }],
+            shortest.paths()
+        );
+    }
+
+    #[test]
+    fn paths_of_a_long_corridor_do_not_overflow_the_stack() {
+        let length = 100_000_u32;
+        let shortest = dijkstra_all(
+            0,
+            |number| (*number < length).then_some((number + 1, 1)),
+            |number| *number == length,
+        )
+        .unwrap();
+
+        let paths = shortest.paths();
+
+        assert_eq!(1, paths.len());
+        assert_eq!(u64::from(length), paths[0].cost);
+        assert_eq!((0..=length).collect::<Vec<_>>(), paths[0].nodes);
+    }
+
+    #[test]
+    fn bfs_works_on_any_graph() {
+        // the fewest steps of +1 and *2 from 1 to 10: 1, 2, 4, 5, 10
+        let path = bfs(
+            1_u64,
+            |number| {
+                [number + 1, number * 2]
+                    .into_iter()
+                    .filter(|next| *next <= 10)
+            },
+            |number| *number == 10,
+        )
+        .unwrap();
+
+        assert_eq!(4, path.cost);
+        assert_eq!(vec![1, 2, 4, 5, 10], path.nodes);
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::{Coordinate, Direction, Grid};
+
+/// A signed position or offset on an unbounded plane, `y` grows downwards like in a [`Grid`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The point one step away in the direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.vector()
+    }
+
+    /// The number of orthogonal steps between two points.
+    pub fn manhattan_distance(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The number of steps between two points if diagonal steps are allowed.
+    pub fn chebyshev_distance(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Point::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl TryFrom<Coordinate> for Point {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Coordinate) -> Result<Self, Self::Error> {
+        Ok(Point::new(value.x.try_into()?, value.y.try_into()?))
+    }
+}
+
+/// Fails for points with a negative component.
+impl TryFrom<Point> for Coordinate {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Point) -> Result<Self, Self::Error> {
+        Ok(Coordinate {
+            x: value.x.try_into()?,
+            y: value.y.try_into()?,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    /// The coordinate of a point, or `None` if the point is outside of the grid.
+    pub fn coordinate(&self, point: &Point) -> Option<Coordinate> {
+        Coordinate::try_from(*point)
+            .ok()
+            .filter(|coord| self.contains(coord))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic_works() {
+        let mut point = Point::new(3, -2);
+
+        assert_eq!(Point::new(4, 0), point + Point::new(1, 2));
+        assert_eq!(Point::new(2, -4), point - Point::new(1, 2));
+        assert_eq!(Point::new(-9, 6), point * -3);
+        assert_eq!(Point::new(-3, 2), -point);
+
+        point += Point::new(1, 1);
+        point -= Point::new(0, 3);
+        assert_eq!(Point::new(4, -4), point);
+    }
+
+    #[test]
+    fn distances_work() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 5);
+
+        assert_eq!(11, a.manhattan_distance(&b));
+        assert_eq!(7, a.chebyshev_distance(&b));
+        assert_eq!(0, a.manhattan_distance(&a));
+        assert_eq!(
+            u64::MAX,
+            Point::new(i64::MIN, 0).manhattan_distance(&Point::new(i64::MAX, 0))
+        );
+    }
+
+    #[test]
+    fn step_works() {
+        let point = Point::ORIGIN
+            .step(Direction::North)
+            .step(Direction::SouthEast);
+        assert_eq!(Point::new(1, 0), point);
+    }
+
+    #[test]
+    fn conversion_into_coordinate_is_checked() {
+        assert_eq!(
+            Ok(Coordinate { x: 2, y: 0 }),
+            Coordinate::try_from(Point::new(2, 0))
+        );
+        assert!(Coordinate::try_from(Point::new(2, -1)).is_err());
+        assert_eq!(
+            Ok(Point::new(4, 7)),
+            Point::try_from(Coordinate { x: 4, y: 7 })
+        );
+        assert!(
+            Point::try_from(Coordinate {
+                x: usize::MAX,
+                y: 0
+            })
+            .is_err()
+        );
+
+        let grid = Grid::filled(3, 2, ());
+        assert_eq!(
+            Some(Coordinate { x: 2, y: 1 }),
+            grid.coordinate(&Point::new(2, 1))
+        );
+        assert_eq!(None, grid.coordinate(&Point::new(3, 1)));
+        assert_eq!(None, grid.coordinate(&Point::new(-1, 1)));
+    }
+}
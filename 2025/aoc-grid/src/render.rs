@@ -0,0 +1,128 @@
+use std::{collections::HashSet, fmt::Display};
+
+use crate::{Coordinate, Grid};
+
+impl<T> Grid<T> {
+    /// Renders the grid as text, one line per row and `cell` mapping every cell to a character.
+    ///
+    /// Parsing the text with the inverse mapping gives back the same grid.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        self.render_cells(|_, value| cell(value))
+    }
+
+    /// Renders the grid like [`Grid::render`], but with `highlight` in place of the highlighted cells.
+    pub fn render_highlighted(
+        &self,
+        cell: impl Fn(&T) -> char,
+        highlighted: &[Coordinate],
+        highlight: char,
+    ) -> String {
+        let highlighted = highlighted.iter().collect::<HashSet<_>>();
+
+        self.render_cells(|coord, value| {
+            if highlighted.contains(&coord) {
+                highlight
+            } else {
+                cell(value)
+            }
+        })
+    }
+
+    fn render_cells(&self, char_at: impl Fn(Coordinate, &T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+
+        for (coord, value) in self.iter() {
+            text.push(char_at(coord, value));
+
+            if coord.x + 1 == self.width {
+                text.push('\n');
+            }
+        }
+
+        text
+    }
+}
+
+/// Renders the grid with the character of every cell.
+impl<T> Display for Grid<T>
+where
+    for<'a> char: From<&'a T>,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(|cell| char::from(cell)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = &'static str;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err("expected `#` or `.`"),
+            }
+        }
+    }
+
+    impl From<&Tile> for char {
+        fn from(value: &Tile) -> Self {
+            match value {
+                Tile::Open => '.',
+                Tile::Wall => '#',
+            }
+        }
+    }
+
+    fn grid() -> impl Strategy<Value = Grid<Tile>> {
+        (1_usize..10, 1_usize..10).prop_flat_map(|(width, height)| {
+            prop::collection::vec(
+                prop_oneof![Just(Tile::Open), Just(Tile::Wall)],
+                width * height,
+            )
+            .prop_map(move |cells| Grid::new(width, height, cells).unwrap())
+        })
+    }
+
+    #[test]
+    fn render_works() {
+        let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let render = grid.render(|cell| char::from_digit(*cell, 10).unwrap());
+        assert_eq!("123\n456\n", render);
+    }
+
+    #[test]
+    fn render_highlighted_works() {
+        let grid = Grid::<Tile>::try_from("..#\n#..\n").unwrap();
+        let highlighted = [Coordinate { x: 2, y: 0 }, Coordinate { x: 1, y: 1 }];
+
+        let render = grid.render_highlighted(|cell| char::from(cell), &highlighted, 'x');
+
+        assert_eq!("..x\n#x.\n", render);
+    }
+
+    #[test]
+    fn display_works() {
+        let grid = Grid::<Tile>::try_from("..#\n#..\n").unwrap();
+        assert_eq!("..#\n#..\n", grid.to_string());
+    }
+
+    proptest! {
+        #[test]
+        fn parsing_the_rendered_grid_gives_the_grid(grid in grid()) {
+            prop_assert_eq!(Ok(grid.clone()), Grid::try_from(grid.to_string().as_str()));
+        }
+    }
+}
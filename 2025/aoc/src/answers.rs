@@ -0,0 +1,126 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use aoc_core::{Answers, Part};
+
+use crate::{RunnerError, workspace::Day};
+
+/// The checked-in answers to the real puzzle inputs, keyed by the package name of the day.
+pub struct AnswerRegistry {
+    days: BTreeMap<String, Answers>,
+}
+
+impl AnswerRegistry {
+    /// Loads the registry from the given file, a missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, RunnerError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        Self::try_from(content.as_str())
+    }
+
+    pub fn get(&self, day: &Day, part: Part) -> Option<&str> {
+        self.days
+            .get(&day.package())
+            .and_then(|answers| answers.get(part))
+    }
+}
+
+impl TryFrom<&str> for AnswerRegistry {
+    type Error = RunnerError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let days = toml::from_str(value).map_err(RunnerError::InvalidAnswerRegistry)?;
+        Ok(Self { days })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Missing,
+}
+
+impl Verdict {
+    pub fn new(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match (expected, actual) {
+            (None, _) => Verdict::Missing,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.map(str::to_string),
+            },
+        }
+    }
+
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "FAIL: expected {expected}, got {actual}"),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "FAIL: expected {expected}, got nothing"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str =
+        "[day-01]\npart-one = \"1086\"\npart-two = \"6268\"\n\n[day-04]\npart-two = \"8538\"\n";
+
+    #[test]
+    fn registry_parsing_works() {
+        let registry = AnswerRegistry::try_from(REGISTRY).expect("registry should be valid");
+
+        assert_eq!(Some("1086"), registry.get(&Day { number: 1 }, Part::One));
+        assert_eq!(Some("6268"), registry.get(&Day { number: 1 }, Part::Two));
+        assert_eq!(None, registry.get(&Day { number: 4 }, Part::One));
+        assert_eq!(Some("8538"), registry.get(&Day { number: 4 }, Part::Two));
+        assert_eq!(None, registry.get(&Day { number: 5 }, Part::One));
+    }
+
+    #[test]
+    fn registry_rejects_unknown_parts() {
+        assert!(AnswerRegistry::try_from("[day-01]\npart-three = \"1\"\n").is_err());
+    }
+
+    #[test]
+    fn verdict_works() {
+        assert_eq!(Verdict::Pass, Verdict::new(Some("42"), Some("42")));
+        assert_eq!(Verdict::Missing, Verdict::new(None, Some("42")));
+        assert_eq!(
+            Verdict::Fail {
+                expected: "42".to_string(),
+                actual: Some("41".to_string())
+            },
+            Verdict::new(Some("42"), Some("41"))
+        );
+        assert!(Verdict::new(Some("42"), None).is_fail());
+    }
+
+    #[test]
+    fn checked_in_registry_is_valid() {
+        let workspace = crate::workspace::Workspace::locate();
+        AnswerRegistry::load(&workspace.answer_registry()).expect("answers.toml should be valid");
+    }
+}
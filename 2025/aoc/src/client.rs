@@ -0,0 +1,98 @@
+use ureq::Agent;
+
+use aoc_core::Part;
+
+use crate::{RunnerError, config::Config, submit::Outcome};
+
+const USER_AGENT: &str = "github.com/SteveBinary/advent-of-code";
+
+/// A client for the Advent of Code website, authenticated with the session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, RunnerError> {
+        Ok(Self {
+            agent: Agent::new_with_defaults(),
+            base_url: config.base_url().trim_end_matches('/').to_string(),
+            session: config.session()?.to_string(),
+        })
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, RunnerError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        let input = self
+            .agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()?
+            .body_mut()
+            .read_to_string()?;
+
+        Ok(input)
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &str,
+    ) -> Result<Outcome, RunnerError> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form([
+                ("level", part.number().to_string().as_str()),
+                ("answer", answer),
+            ])?
+            .body_mut()
+            .read_to_string()?;
+
+        Ok(Outcome::from_response(&response))
+    }
+}
+
+impl From<ureq::Error> for RunnerError {
+    fn from(error: ureq::Error) -> Self {
+        RunnerError::Http(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    #[test]
+    fn submit_works() {
+        let server = StubServer::serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>"
+                .to_string(),
+        )]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: Some(server.base_url.clone()),
+        };
+
+        let outcome = Client::new(&config)
+            .unwrap()
+            .submit(2025, 3, Part::Two, "17229")
+            .unwrap();
+        let requests = server.requests();
+
+        assert_eq!(Outcome::TooLow, outcome);
+        assert!(requests[0].starts_with("POST /2025/day/3/answer HTTP/1.1"));
+        assert!(requests[0].ends_with("level=2&answer=17229"));
+    }
+}
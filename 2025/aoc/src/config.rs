@@ -0,0 +1,100 @@
+use std::{env, fs, io, path::PathBuf};
+
+use serde::Deserialize;
+
+use crate::RunnerError;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The user configuration for talking to the Advent of Code website.
+///
+/// It is read from `$AOC_CONFIG`, `$XDG_CONFIG_HOME/aoc/config.toml` or `~/.config/aoc/config.toml`,
+/// and the environment variables `AOC_SESSION` and `AOC_BASE_URL` take precedence over it.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, RunnerError> {
+        let file = match config_path() {
+            Some(path) => match fs::read_to_string(path) {
+                Ok(content) => Self::try_from(content.as_str())?,
+                Err(error) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+                Err(error) => return Err(error.into()),
+            },
+            None => Self::default(),
+        };
+
+        Ok(file.with_overrides(env::var("AOC_SESSION").ok(), env::var("AOC_BASE_URL").ok()))
+    }
+
+    fn with_overrides(self, session: Option<String>, base_url: Option<String>) -> Self {
+        Self {
+            session: session.or(self.session),
+            base_url: base_url.or(self.base_url),
+        }
+    }
+
+    pub fn session(&self) -> Result<&str, RunnerError> {
+        self.session
+            .as_deref()
+            .map(str::trim)
+            .filter(|session| !session.is_empty())
+            .ok_or(RunnerError::MissingSession)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL)
+    }
+}
+
+impl TryFrom<&str> for Config {
+    type Error = RunnerError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        toml::from_str(value).map_err(RunnerError::InvalidConfig)
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("AOC_CONFIG") {
+        return Some(path.into());
+    }
+
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::home_dir().map(|home| home.join(".config")))?;
+
+    Some(config_dir.join("aoc").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_parsing_works() {
+        let config = Config::try_from("session = \"abc\"\nbase-url = \"http://localhost:8080\"\n")
+            .expect("config should be valid");
+        assert_eq!("abc", config.session().unwrap());
+        assert_eq!("http://localhost:8080", config.base_url());
+    }
+
+    #[test]
+    fn environment_overrides_config() {
+        let config = Config::try_from("session = \"abc\"\n")
+            .unwrap()
+            .with_overrides(Some("def".to_string()), None);
+        assert_eq!("def", config.session().unwrap());
+        assert_eq!(DEFAULT_BASE_URL, config.base_url());
+    }
+
+    #[test]
+    fn empty_session_is_missing() {
+        let config = Config::try_from("session = \" \"\n").unwrap();
+        assert!(matches!(config.session(), Err(RunnerError::MissingSession)));
+    }
+}
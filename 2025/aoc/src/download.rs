@@ -0,0 +1,90 @@
+use std::{fs, io, path::Path};
+
+use crate::{RunnerError, client::Client, config::Config, workspace::Day};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Download {
+    Cached,
+    Downloaded,
+}
+
+/// Downloads the puzzle input of a day into `path`, unless it is already there.
+///
+/// An empty file does not count as cached, because new days are created with an empty input.txt.
+pub fn download_input(
+    config: &Config,
+    year: u16,
+    day: &Day,
+    path: &Path,
+) -> Result<Download, RunnerError> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() > 0 => return Ok(Download::Cached),
+        Ok(_) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => return Err(error.into()),
+    }
+
+    let input = Client::new(config)?.input(year, day.number)?;
+    fs::write(path, input)?;
+
+    Ok(Download::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::StubServer;
+
+    #[test]
+    fn download_input_works_and_is_cached() {
+        let server = StubServer::serve(vec![(200, "1-2\n3-4\n".to_string())]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: Some(server.base_url.clone()),
+        };
+        let path = std::env::temp_dir().join(format!("aoc-download-{}.txt", std::process::id()));
+        let day = Day { number: 5 };
+
+        let first = download_input(&config, 2025, &day, &path).unwrap();
+        let second = download_input(&config, 2025, &day, &path).unwrap();
+        let input = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let requests = server.requests();
+
+        assert_eq!(Download::Downloaded, first);
+        assert_eq!(Download::Cached, second);
+        assert_eq!("1-2\n3-4\n", input);
+        assert_eq!(1, requests.len());
+        assert!(requests[0].starts_with("GET /2025/day/5/input HTTP/1.1"));
+        assert!(
+            requests[0]
+                .to_lowercase()
+                .contains("cookie: session=secret")
+        );
+    }
+
+    #[test]
+    fn download_input_fails_on_http_errors() {
+        let server = StubServer::serve(vec![(404, "not yet".to_string())]);
+        let config = Config {
+            session: Some("secret".to_string()),
+            base_url: Some(server.base_url.clone()),
+        };
+        let path =
+            std::env::temp_dir().join(format!("aoc-download-404-{}.txt", std::process::id()));
+
+        let result = download_input(&config, 2025, &Day { number: 25 }, &path);
+
+        assert!(matches!(result, Err(RunnerError::Http(_))));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn download_input_requires_a_session() {
+        let path =
+            std::env::temp_dir().join(format!("aoc-download-none-{}.txt", std::process::id()));
+        let result = download_input(&Config::default(), 2025, &Day { number: 1 }, &path);
+        assert!(matches!(result, Err(RunnerError::MissingSession)));
+    }
+}
@@ -0,0 +1,31 @@
+use std::{path::Path, process::Command};
+
+/// The commit that is checked out in the repository containing `dir`.
+pub fn head_commit(dir: &Path) -> Option<String> {
+    resolve_commit(dir, "HEAD")
+}
+
+/// Resolves any revision git understands, like a tag, a branch or a short hash, to a full commit hash.
+pub fn resolve_commit(dir: &Path, revision: &str) -> Option<String> {
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{revision}^{{commit}}"))
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Whether the working tree of the repository containing `dir` has uncommitted changes.
+pub fn is_dirty(dir: &Path) -> bool {
+    Command::new("git")
+        .current_dir(dir)
+        .args(["status", "--porcelain"])
+        .output()
+        .is_ok_and(|output| !output.stdout.is_empty())
+}
@@ -0,0 +1,272 @@
+use std::{
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    marker::PhantomData,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_core::Phase;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+
+use crate::{RunnerError, runner::DayBenchReport};
+
+/// A single `aoc bench` run of the history.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub commit: String,
+    pub dirty: bool,
+    pub date: String,
+    pub warmup: u32,
+    pub samples: u32,
+    pub days: Vec<DayBenchReport>,
+}
+
+impl HistoryEntry {
+    /// Whether this entry was recorded at the given commit or date, both may be shortened.
+    pub fn matches(&self, commit_or_date: &str) -> bool {
+        self.commit.starts_with(commit_or_date) || self.date.starts_with(commit_or_date)
+    }
+
+    pub fn label(&self) -> String {
+        let commit = &self.commit[..self.commit.len().min(10)];
+        let dirty = if self.dirty { "+dirty" } else { "" };
+        format!("{commit}{dirty} ({})", self.date)
+    }
+}
+
+/// A local, append only store, like the benchmark results or the submitted answers,
+/// with one JSON document per line.
+pub struct History<T> {
+    path: PathBuf,
+    entries: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> History<T> {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            entries: PhantomData,
+        }
+    }
+
+    pub fn append(&self, entry: &T) -> Result<(), RunnerError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        let line = serde_json::to_string(entry).expect("history entry should be serializable");
+        writeln!(file, "{line}")?;
+
+        Ok(())
+    }
+
+    /// All entries, oldest first.
+    pub fn entries(&self) -> Result<Vec<T>, RunnerError> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error.into()),
+        };
+
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| serde_json::from_str(line).map_err(RunnerError::InvalidHistory))
+            .collect()
+    }
+}
+
+/// The change of the median time of one phase of a day between two history entries.
+#[derive(Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: Phase,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// How many times longer the current run took compared to the baseline.
+    pub fn ratio(&self) -> f64 {
+        self.current.as_secs_f64() / self.baseline.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+
+    pub fn is_regression(&self, threshold_percent: f64) -> bool {
+        self.ratio() > 1.0 + threshold_percent / 100.0
+    }
+
+    pub fn change(&self) -> Change {
+        Change(self.ratio())
+    }
+}
+
+pub struct Change(f64);
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 < 1.0 {
+            write!(f, "{:.2}x faster", 1.0 / self.0)
+        } else if self.0 > 1.0 {
+            write!(f, "{:.2}x slower", self.0)
+        } else {
+            write!(f, "unchanged")
+        }
+    }
+}
+
+/// Compares every phase that was benchmarked in both entries.
+pub fn compare(baseline: &HistoryEntry, current: &HistoryEntry) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+
+    for current_day in current.days.iter() {
+        let Some(baseline_day) = baseline.days.iter().find(|day| day.day == current_day.day) else {
+            continue;
+        };
+
+        for current_timings in current_day.report.phases.iter() {
+            if let Some(baseline_timings) = baseline_day
+                .report
+                .phases
+                .iter()
+                .find(|timings| timings.phase == current_timings.phase)
+            {
+                comparisons.push(Comparison {
+                    day: current_day.day,
+                    phase: current_timings.phase,
+                    baseline: baseline_timings.median(),
+                    current: current_timings.median(),
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// The current time as an RFC 3339 timestamp in UTC, e.g. `2025-12-06T18:30:00Z`.
+pub fn now_rfc3339() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    format_rfc3339(seconds)
+}
+
+fn format_rfc3339(seconds_since_epoch: u64) -> String {
+    let days = (seconds_since_epoch / 86_400) as i64;
+    let seconds_of_day = seconds_since_epoch % 86_400;
+
+    // civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds_of_day / 3_600,
+        seconds_of_day % 3_600 / 60,
+        seconds_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{BenchReport, PhaseTimings};
+
+    use super::*;
+
+    fn entry(commit: &str, medians: [u64; 2]) -> HistoryEntry {
+        let phases = [Phase::Parse, Phase::PartOne]
+            .into_iter()
+            .zip(medians)
+            .map(|(phase, median_ns)| PhaseTimings {
+                phase,
+                samples: 1,
+                min_ns: median_ns,
+                median_ns,
+                max_ns: median_ns,
+            })
+            .collect();
+
+        HistoryEntry {
+            commit: commit.to_string(),
+            dirty: false,
+            date: "2025-12-06T18:30:00Z".to_string(),
+            warmup: 0,
+            samples: 1,
+            days: vec![DayBenchReport {
+                day: 5,
+                report: BenchReport { phases },
+            }],
+        }
+    }
+
+    #[test]
+    fn format_rfc3339_works() {
+        assert_eq!("1970-01-01T00:00:00Z", format_rfc3339(0));
+        assert_eq!("2000-02-29T12:34:56Z", format_rfc3339(951_827_696));
+        assert_eq!("2025-12-06T18:30:00Z", format_rfc3339(1_765_045_800));
+    }
+
+    #[test]
+    fn entry_matches_commit_and_date_prefixes() {
+        let entry = entry("0123456789abcdef", [1, 1]);
+        assert!(entry.matches("0123456"));
+        assert!(entry.matches("2025-12-06"));
+        assert!(!entry.matches("abcdef"));
+    }
+
+    #[test]
+    fn compare_works() {
+        let baseline = entry("a", [100, 200]);
+        let current = entry("b", [50, 300]);
+
+        let comparisons = compare(&baseline, &current);
+
+        assert_eq!(2, comparisons.len());
+        assert_eq!("2.00x faster", comparisons[0].change().to_string());
+        assert!(!comparisons[0].is_regression(10.0));
+        assert_eq!("1.50x slower", comparisons[1].change().to_string());
+        assert!(comparisons[1].is_regression(10.0));
+        assert!(!comparisons[1].is_regression(60.0));
+    }
+
+    #[test]
+    fn history_round_trip_works() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let history = History::<HistoryEntry>::new(&path);
+
+        history.append(&entry("a", [1, 2])).unwrap();
+        history.append(&entry("b", [3, 4])).unwrap();
+
+        let commits = history
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|entry| entry.commit)
+            .collect::<Vec<_>>();
+
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(vec!["a", "b"], commits);
+    }
+}
@@ -0,0 +1,826 @@
+mod answers;
+mod client;
+mod config;
+mod download;
+mod git;
+mod history;
+mod pool;
+mod runner;
+mod scaffold;
+#[cfg(test)]
+mod stub;
+mod submit;
+mod table;
+mod workspace;
+
+use std::{
+    fmt::Display, fs, io, num::NonZeroUsize, path::PathBuf, process::ExitCode, time::Duration,
+};
+
+use aoc_core::{
+    Answers, BenchConfig, DayOutcome, DayReport, ErrorKind, Format, Part, format_bytes,
+};
+use clap::{Args, Parser, Subcommand};
+
+use answers::*;
+use client::*;
+use config::*;
+use download::*;
+use history::*;
+use pool::*;
+use runner::*;
+use submit::*;
+use table::*;
+use workspace::*;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions of every year in this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day or every day in parallel and print a summary of the answers
+    Run(RunArgs),
+
+    /// Run days on their input.txt and compare the answers against answers.toml
+    Verify(VerifyArgs),
+
+    /// Time parsing and both parts of days on their input.txt
+    Bench(BenchArgs),
+
+    /// Generate a random input for a day, e.g. to stress-test or benchmark it
+    Generate(GenerateArgs),
+
+    /// Compare the latest benchmark results against a baseline from the history
+    Compare(CompareArgs),
+
+    /// Create the crate of a new day from the template crate
+    NewDay(NewDayArgs),
+
+    /// Create the workspace of a new year with the setup and template crate of this workspace
+    NewYear(NewYearArgs),
+
+    /// Download the puzzle input of a day into its input.txt, unless it is already there
+    Download(DownloadArgs),
+
+    /// Submit an answer to the website, unless earlier guesses already rule it out
+    Submit(SubmitArgs),
+}
+
+/// The year and day to work on, e.g. `2025 4`, or only a day of this workspace's year, e.g. `4`.
+#[derive(Args)]
+struct Selection {
+    /// The year of the puzzles, defaults to the year of this workspace; a lone 1 to 25 is a day of that year
+    #[arg(value_name = "YEAR", value_parser = parse_year_or_day)]
+    year: Option<YearOrDay>,
+
+    /// The day, defaults to every day of the year
+    #[arg(requires = "year")]
+    day: Option<u8>,
+}
+
+/// The first number of a selection, which is a year unless it could only be a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum YearOrDay {
+    Year(u16),
+    Day(u8),
+}
+
+fn parse_year_or_day(value: &str) -> Result<YearOrDay, String> {
+    match value.parse::<u16>() {
+        Ok(day @ 1..=25) => Ok(YearOrDay::Day(day as u8)),
+        Ok(year @ 2015..) => Ok(YearOrDay::Year(year)),
+        _ => Err("expected a year from 2015 on or a day from 1 to 25".to_string()),
+    }
+}
+
+impl Selection {
+    /// The selected year and day, where `None` is the year of this workspace or every day.
+    fn resolve(&self) -> Result<(Option<u16>, Option<u8>), RunnerError> {
+        match (self.year, self.day) {
+            (Some(YearOrDay::Day(_)), Some(_)) => Err(RunnerError::InvalidSelection(
+                "a day cannot follow a day, pass the year first, e.g. `2025 4`",
+            )),
+            (Some(YearOrDay::Day(day)), None) => Ok((None, Some(day))),
+            (Some(YearOrDay::Year(year)), day) => Ok((Some(year), day)),
+            (None, day) => Ok((None, day)),
+        }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Only run the given part (1 or 2)
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// Read the puzzle input from this file or from stdin for `-` instead of the day's input.txt
+    #[arg(long, value_name = "PATH", requires = "year")]
+    input: Option<PathBuf>,
+
+    /// Number of days to run at once
+    #[arg(long, default_value_t = default_jobs())]
+    jobs: NonZeroUsize,
+
+    /// Count the allocations, allocated bytes and peak heap usage of every phase
+    #[arg(long)]
+    alloc_stats: bool,
+
+    /// The output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of untimed runs of each phase before sampling
+    #[arg(long, default_value_t = 1)]
+    warmup: u32,
+
+    /// Number of timed runs of each phase
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    samples: u32,
+
+    /// The output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Do not record the results in the benchmark history
+    #[arg(long)]
+    no_save: bool,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The year of the puzzle
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    /// The day to generate an input for
+    day: u8,
+
+    /// The size of the input, e.g. the number of lines
+    #[arg(long)]
+    size: NonZeroUsize,
+
+    /// The seed of the random numbers, the same seed always generates the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Write the input to this file instead of stdout
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// The commit, or the date in the form YYYY-MM-DD, of the benchmark run to compare against
+    baseline: String,
+
+    /// The commit or date of the run to compare, defaults to the latest run
+    #[arg(long)]
+    current: Option<String>,
+
+    /// Fail if any phase of any day got slower by more than this many percent
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// The year whose benchmark history to compare, defaults to the year of this workspace
+    #[arg(long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct NewDayArgs {
+    /// The number of the day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The year to create the day in, defaults to the year of this workspace
+    #[arg(long)]
+    year: Option<u16>,
+}
+
+#[derive(Args)]
+struct NewYearArgs {
+    /// The year to create the workspace for
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+}
+
+#[derive(Args)]
+struct DownloadArgs {
+    /// The day to download the input for
+    day: u8,
+
+    /// The year of the puzzle, defaults to the year of this workspace
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// The URL of the Advent of Code website, overrides AOC_BASE_URL and the config file
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The day the answer is for
+    day: u8,
+
+    /// The part the answer is for (1 or 2)
+    part: Part,
+
+    /// The answer to submit
+    answer: String,
+
+    /// The year of the puzzle, defaults to the year of this workspace
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// The URL of the Advent of Code website, overrides AOC_BASE_URL and the config file
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum RunnerError {
+    Io(io::Error),
+    DayNotFound(u8),
+    YearNotFound { year: u16, years: Vec<u16> },
+    InvalidAnswerRegistry(toml::de::Error),
+    BuildFailed,
+    DayFailed { day: Day, stderr: String },
+    InvalidDayOutput { day: Day, error: serde_json::Error },
+    InvalidHistory(serde_json::Error),
+    NoBenchmarkRun(String),
+    DayAlreadyExists(Day),
+    YearAlreadyExists(u16),
+    InvalidSelection(&'static str),
+    NoWorkspaceMembers,
+    UnknownYear,
+    MissingSession,
+    InvalidConfig(toml::de::Error),
+    Http(ureq::Error),
+    GuessRefused(Refusal),
+}
+
+impl Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunnerError::Io(error) => write!(f, "{error}"),
+            RunnerError::DayNotFound(number) => write!(f, "there is no crate for day {number}"),
+            RunnerError::YearNotFound { year, years } => {
+                let years = years
+                    .iter()
+                    .map(u16::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "there is no workspace for {year}, the years are {years}")
+            }
+            RunnerError::InvalidAnswerRegistry(error) => {
+                write!(f, "the answer registry is invalid: {error}")
+            }
+            RunnerError::BuildFailed => write!(f, "building the days failed"),
+            RunnerError::DayFailed { day, stderr } => {
+                write!(f, "{} failed:\n{stderr}", day.package())
+            }
+            RunnerError::InvalidDayOutput { day, error } => {
+                write!(f, "{} printed unexpected output: {error}", day.package())
+            }
+            RunnerError::InvalidHistory(error) => {
+                write!(f, "the benchmark history is invalid: {error}")
+            }
+            RunnerError::NoBenchmarkRun(revision) => {
+                write!(f, "the benchmark history has no run for '{revision}'")
+            }
+            RunnerError::DayAlreadyExists(day) => write!(f, "{} already exists", day.package()),
+            RunnerError::YearAlreadyExists(year) => write!(f, "{year} already exists"),
+            RunnerError::InvalidSelection(reason) => write!(f, "{reason}"),
+            RunnerError::NoWorkspaceMembers => {
+                write!(f, "the workspace Cargo.toml has no members list")
+            }
+            RunnerError::UnknownYear => {
+                write!(
+                    f,
+                    "the year cannot be derived from the workspace, pass --year"
+                )
+            }
+            RunnerError::MissingSession => write!(
+                f,
+                "no session token, set AOC_SESSION or `session` in ~/.config/aoc/config.toml"
+            ),
+            RunnerError::InvalidConfig(error) => write!(f, "the config file is invalid: {error}"),
+            RunnerError::Http(error) => write!(f, "request failed: {error}"),
+            RunnerError::GuessRefused(refusal) => write!(f, "not submitted, {refusal}"),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let repository = Repository::locate();
+
+    let result = match cli.command {
+        Command::Run(args) => run(&repository, &args),
+        Command::Verify(args) => verify(&repository, &args),
+        Command::Bench(args) => bench(&repository, &args),
+        Command::Generate(args) => generate(&repository, &args),
+        Command::Compare(args) => compare_history(&repository, &args),
+        Command::NewDay(args) => new_day(&repository, &args),
+        Command::NewYear(args) => new_year(&repository, &args),
+        Command::Download(args) => download(&repository, &args),
+        Command::Submit(args) => submit(&repository, &args),
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// The workspace of the given year, or the workspace of this runner if no year is given.
+fn workspace(repository: &Repository, year: Option<u16>) -> Result<Workspace, RunnerError> {
+    match year {
+        Some(year) => repository.workspace(year),
+        None => Ok(Workspace::locate()),
+    }
+}
+
+/// The workspace and the days of a selection.
+fn select(
+    repository: &Repository,
+    selection: &Selection,
+) -> Result<(Workspace, Vec<Day>), RunnerError> {
+    let (year, day) = selection.resolve()?;
+    let workspace = workspace(repository, year)?;
+    let days = workspace.select_days(day)?;
+
+    Ok((workspace, days))
+}
+
+fn run(repository: &Repository, args: &RunArgs) -> Result<ExitCode, RunnerError> {
+    if args.input.is_some() && args.selection.resolve()?.1.is_none() {
+        return Err(RunnerError::InvalidSelection("--input needs a day"));
+    }
+    let (workspace, days) = select(repository, &args.selection)?;
+    let registry = AnswerRegistry::load(&workspace.answer_registry())?;
+
+    let features: &[&str] = if args.alloc_stats {
+        &[ALLOC_STATS_FEATURE]
+    } else {
+        &[]
+    };
+    workspace.build(&days, features)?;
+
+    // every day runs in its own process, a panicking job only fails the report of its day
+    let reports = run_parallel(&days, args.jobs.get(), |day| {
+        run_day(&workspace, day, args.part, args.input.as_deref())
+    })
+    .into_iter()
+    .zip(days.iter())
+    .map(|(result, day)| match result {
+        Ok(Ok(report)) => report,
+        Ok(Err(error)) => DayReport::error(day.number, ErrorKind::Crash, error.to_string()),
+        Err(panic) => DayReport::error(
+            day.number,
+            ErrorKind::Crash,
+            format!("running the day panicked: {panic}"),
+        ),
+    })
+    .collect::<Vec<_>>();
+
+    let parts = args.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let mut table = Table::new(["day", "part one", "part two", "time", "status"]);
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for (day, report) in days.iter().zip(reports.iter()) {
+        let row = match &report.outcome {
+            DayOutcome::Parts(results) => {
+                let answers = report.answers();
+                let time = results
+                    .iter()
+                    .map(|result| Duration::from_nanos(result.duration_ns))
+                    .sum::<Duration>();
+
+                // answers to another input than input.txt cannot be checked
+                let status = match args.input {
+                    Some(_) => "-".to_string(),
+                    None => status(&registry, day, &parts, &answers),
+                };
+
+                if status.starts_with("FAIL") {
+                    exit_code = ExitCode::FAILURE;
+                }
+
+                Part::ALL
+                    .iter()
+                    .map(|part| answers.get(*part).unwrap_or("-").to_string())
+                    .chain([format!("{time:.2?}"), status])
+                    .collect()
+            }
+            DayOutcome::Error(error) => {
+                if args.format == Format::Text {
+                    eprintln!("error: day {}: {error}", report.day);
+                }
+
+                exit_code = ExitCode::FAILURE;
+                vec![
+                    "failed".to_string(),
+                    "failed".to_string(),
+                    "-".to_string(),
+                    "error".to_string(),
+                ]
+            }
+        };
+
+        table.add_row([vec![day.number.to_string()], row].concat());
+    }
+
+    match args.format {
+        Format::Text => {
+            print!("{table}");
+
+            if args.alloc_stats {
+                println!();
+                print!("{}", allocations_table(&reports));
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&reports).expect("day reports should be serializable")
+        ),
+    }
+
+    Ok(exit_code)
+}
+
+fn allocations_table(reports: &[DayReport]) -> Table {
+    let mut table = Table::new(["day", "phase", "allocations", "allocated", "peak heap"]);
+
+    for report in reports.iter() {
+        for allocations in report.allocations.iter() {
+            table.add_row(vec![
+                report.day.to_string(),
+                allocations.phase.to_string(),
+                allocations.allocations.to_string(),
+                format_bytes(allocations.bytes),
+                format_bytes(allocations.peak_heap_bytes),
+            ]);
+        }
+    }
+
+    table
+}
+
+/// Sums up how the answers of the given parts compare to the registry:
+/// the first failing verdict, `missing` if an answer is not recorded, or `pass`.
+fn status(registry: &AnswerRegistry, day: &Day, parts: &[Part], answers: &Answers) -> String {
+    let verdicts = parts
+        .iter()
+        .map(|part| Verdict::new(registry.get(day, *part), answers.get(*part)))
+        .collect::<Vec<_>>();
+
+    if let Some(fail) = verdicts.iter().find(|verdict| verdict.is_fail()) {
+        fail.to_string()
+    } else if verdicts.contains(&Verdict::Missing) {
+        Verdict::Missing.to_string()
+    } else {
+        Verdict::Pass.to_string()
+    }
+}
+
+fn verify(repository: &Repository, args: &VerifyArgs) -> Result<ExitCode, RunnerError> {
+    let (workspace, days) = select(repository, &args.selection)?;
+    let registry = AnswerRegistry::load(&workspace.answer_registry())?;
+
+    workspace.build(&days, &[])?;
+
+    let mut table = Table::new(["day", "part one", "part two"]);
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for day in days.iter() {
+        let report = run_day(&workspace, day, None, None)?;
+
+        let row = match &report.outcome {
+            DayOutcome::Parts(_) => Part::ALL
+                .iter()
+                .map(|part| {
+                    let verdict =
+                        Verdict::new(registry.get(day, *part), report.answers().get(*part));
+
+                    if verdict.is_fail() {
+                        exit_code = ExitCode::FAILURE;
+                    }
+
+                    verdict.to_string()
+                })
+                .collect(),
+            DayOutcome::Error(error) => {
+                eprintln!("error: day {}: {error}", day.number);
+                exit_code = ExitCode::FAILURE;
+                vec!["failed".to_string(); Part::ALL.len()]
+            }
+        };
+
+        table.add_row([vec![day.number.to_string()], row].concat());
+    }
+
+    print!("{table}");
+
+    Ok(exit_code)
+}
+
+fn bench(repository: &Repository, args: &BenchArgs) -> Result<ExitCode, RunnerError> {
+    let (workspace, days) = select(repository, &args.selection)?;
+
+    workspace.build(&days, &[])?;
+
+    let config = BenchConfig {
+        warmup: args.warmup,
+        samples: args.samples,
+    };
+
+    let mut reports = Vec::new();
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for day in days.iter() {
+        match bench_day(&workspace, day, &config) {
+            Ok(report) => reports.push(report),
+            Err(error) => {
+                eprintln!("error: {error}");
+                exit_code = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    match args.format {
+        Format::Text => {
+            let mut table = Table::new(["day", "phase", "min", "median", "max"]);
+
+            for DayBenchReport { day, report } in reports.iter() {
+                for timings in report.phases.iter() {
+                    table.add_row(vec![
+                        day.to_string(),
+                        timings.phase.to_string(),
+                        format!("{:.2?}", timings.min()),
+                        format!("{:.2?}", timings.median()),
+                        format!("{:.2?}", timings.max()),
+                    ]);
+                }
+            }
+
+            print!("{table}");
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&reports).expect("bench reports should be serializable")
+        ),
+    }
+
+    if !args.no_save && !reports.is_empty() {
+        History::new(workspace.bench_history()).append(&HistoryEntry {
+            commit: workspace.git_commit(),
+            dirty: workspace.is_dirty(),
+            date: now_rfc3339(),
+            warmup: config.warmup,
+            samples: config.samples,
+            days: reports,
+        })?;
+    }
+
+    Ok(exit_code)
+}
+
+fn generate(repository: &Repository, args: &GenerateArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = repository.workspace(args.year)?;
+    let day = workspace.day(args.day)?;
+
+    workspace.build(&[day], &[])?;
+
+    let input = generate_input(&workspace, &day, args.size.get(), args.seed)?;
+
+    match &args.output {
+        Some(path) => {
+            fs::write(path, input)?;
+            println!("generated {}", path.display());
+        }
+        None => print!("{input}"),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn compare_history(repository: &Repository, args: &CompareArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = workspace(repository, args.year)?;
+    let entries = History::new(workspace.bench_history()).entries()?;
+
+    // the baseline is searched among the runs before the current one,
+    // so that two runs of the same commit can be compared
+    let find_latest = |entries: &'_ [HistoryEntry], revision: &str| {
+        let commit = workspace.resolve_commit(revision);
+
+        entries
+            .iter()
+            .rposition(|entry| entry.matches(&commit) || entry.matches(revision))
+            .ok_or_else(|| RunnerError::NoBenchmarkRun(revision.to_string()))
+    };
+
+    let current_index = match args.current.as_deref() {
+        Some(revision) => find_latest(&entries, revision)?,
+        None => entries
+            .len()
+            .checked_sub(1)
+            .ok_or_else(|| RunnerError::NoBenchmarkRun("latest".to_string()))?,
+    };
+    let baseline_index = find_latest(&entries[..current_index], &args.baseline)?;
+
+    let baseline = &entries[baseline_index];
+    let current = &entries[current_index];
+
+    println!("baseline: {}", baseline.label());
+    println!("current:  {}", current.label());
+    println!();
+
+    let mut table = Table::new(["day", "phase", "baseline", "current", "change", "status"]);
+    let mut exit_code = ExitCode::SUCCESS;
+
+    for comparison in compare(baseline, current) {
+        let regression = comparison.is_regression(args.threshold);
+
+        if regression {
+            exit_code = ExitCode::FAILURE;
+        }
+
+        table.add_row(vec![
+            comparison.day.to_string(),
+            comparison.phase.to_string(),
+            format!("{:.2?}", comparison.baseline),
+            format!("{:.2?}", comparison.current),
+            comparison.change().to_string(),
+            if regression { "REGRESSION" } else { "ok" }.to_string(),
+        ]);
+    }
+
+    print!("{table}");
+
+    Ok(exit_code)
+}
+
+fn new_day(repository: &Repository, args: &NewDayArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = workspace(repository, args.year)?;
+    let day = Day { number: args.day };
+
+    scaffold::new_day(&workspace, &day)?;
+
+    println!(
+        "created {} - paste the puzzle input into {}",
+        day.package(),
+        workspace
+            .root()
+            .join(day.package())
+            .join("input.txt")
+            .display()
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn new_year(repository: &Repository, args: &NewYearArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = scaffold::new_year(repository, &Workspace::locate(), args.year)?;
+
+    println!(
+        "created {} - add days with `aoc new-day <day> --year {}`",
+        workspace.root().display(),
+        args.year
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn download(repository: &Repository, args: &DownloadArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = workspace(repository, args.year)?;
+    let day = workspace.day(args.day)?;
+    let year = args
+        .year
+        .or_else(|| workspace.year())
+        .ok_or(RunnerError::UnknownYear)?;
+    let config = load_config(args.base_url.as_deref())?;
+
+    let path = workspace.input(&day);
+
+    match download_input(&config, year, &day, &path)? {
+        Download::Cached => println!("{} is already downloaded", path.display()),
+        Download::Downloaded => println!("downloaded {}", path.display()),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn submit(repository: &Repository, args: &SubmitArgs) -> Result<ExitCode, RunnerError> {
+    let workspace = workspace(repository, args.year)?;
+    let year = args
+        .year
+        .or_else(|| workspace.year())
+        .ok_or(RunnerError::UnknownYear)?;
+    let answer = args.answer.trim();
+
+    let history = History::<Submission>::new(workspace.submission_history());
+    check_guess(
+        &history.entries()?,
+        year,
+        args.day,
+        args.part.number(),
+        answer,
+    )
+    .map_err(RunnerError::GuessRefused)?;
+
+    let config = load_config(args.base_url.as_deref())?;
+    let outcome = Client::new(&config)?.submit(year, args.day, args.part, answer)?;
+
+    println!("{outcome}");
+
+    let exit_code = if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    };
+
+    history.append(&Submission {
+        year,
+        day: args.day,
+        part: args.part.number(),
+        answer: answer.to_string(),
+        date: now_rfc3339(),
+        outcome,
+    })?;
+
+    Ok(exit_code)
+}
+
+fn load_config(base_url: Option<&str>) -> Result<Config, RunnerError> {
+    let mut config = Config::load()?;
+
+    if let Some(base_url) = base_url {
+        config.base_url = Some(base_url.to_string());
+    }
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(args: &[&str]) -> Result<(Option<u16>, Option<u8>), String> {
+        let cli = Cli::try_parse_from(args).map_err(|error| error.to_string())?;
+        let Command::Run(args) = cli.command else {
+            panic!("not a run command");
+        };
+        args.selection.resolve().map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn a_lone_day_is_a_day_of_this_workspace() {
+        assert_eq!(Ok((None, Some(4))), selection(&["aoc", "run", "4"]));
+        assert_eq!(Ok((None, Some(25))), selection(&["aoc", "run", "25"]));
+    }
+
+    #[test]
+    fn a_year_can_be_followed_by_a_day() {
+        assert_eq!(
+            Ok((Some(2025), Some(4))),
+            selection(&["aoc", "run", "2025", "4"])
+        );
+        assert_eq!(Ok((Some(2024), None)), selection(&["aoc", "run", "2024"]));
+        assert_eq!(Ok((None, None)), selection(&["aoc", "run"]));
+    }
+
+    #[test]
+    fn invalid_selections_are_rejected() {
+        assert!(selection(&["aoc", "run", "4", "5"]).is_err());
+        assert!(selection(&["aoc", "run", "26"]).is_err());
+        assert!(selection(&["aoc", "run", "2014"]).is_err());
+        assert!(selection(&["aoc", "run", "--input", "input.txt"]).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+};
+
+use aoc_core::panic_message;
+
+/// The number of jobs to run at once if not given explicitly.
+pub fn default_jobs() -> NonZeroUsize {
+    thread::available_parallelism().unwrap_or(NonZeroUsize::MIN)
+}
+
+/// Applies `job` to every item on up to `jobs` threads and returns the results in the order of the items.
+///
+/// A panicking job does not affect the others, its result is the panic message.
+pub fn run_parallel<T, R>(
+    items: &[T],
+    jobs: usize,
+    job: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>>
+where
+    T: Sync,
+    R: Send,
+{
+    let next_index = AtomicUsize::new(0);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result =
+                        panic::catch_unwind(AssertUnwindSafe(|| job(item))).map_err(panic_message);
+
+                    results
+                        .lock()
+                        .expect("no job should panic while holding the lock")[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("no job should panic while holding the lock")
+        .into_iter()
+        .map(|result| result.expect("every item should have been processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn run_parallel_keeps_the_order() {
+        let items = (0..20).collect::<Vec<u64>>();
+
+        // later items finish first
+        let results = run_parallel(&items, 4, |item| {
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        });
+
+        let expected = items.iter().map(|item| Ok(item * 2)).collect::<Vec<_>>();
+        assert_eq!(expected, results);
+    }
+
+    #[test]
+    fn run_parallel_isolates_panics() {
+        let items = (0..8).collect::<Vec<u32>>();
+
+        let results = run_parallel(&items, 3, |item| {
+            if *item == 5 {
+                panic!("day {item} exploded");
+            }
+            *item
+        });
+
+        assert_eq!(Err("day 5 exploded".to_string()), results[5]);
+        assert_eq!(7, results.iter().filter(|result| result.is_ok()).count());
+    }
+
+    #[test]
+    fn run_parallel_without_items_works() {
+        let results = run_parallel(&[] as &[u8], 4, |item| *item);
+        assert!(results.is_empty());
+    }
+}
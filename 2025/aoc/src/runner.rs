@@ -0,0 +1,119 @@
+use std::{
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use aoc_core::{BenchConfig, BenchReport, DayOutcome, DayReport, ErrorKind, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    RunnerError,
+    workspace::{Day, Workspace},
+};
+
+/// The feature that makes the days report the allocations of every phase.
+pub const ALLOC_STATS_FEATURE: &str = "aoc-core/alloc-stats";
+
+/// Runs the already built binary of a day and collects the report it prints.
+///
+/// A day that fails without reporting a structured error is reported as crashed.
+pub fn run_day(
+    workspace: &Workspace,
+    day: &Day,
+    part: Option<Part>,
+    input: Option<&Path>,
+) -> Result<DayReport, RunnerError> {
+    let mut command = Command::new(workspace.executable(day));
+    command.args(["--format", "json"]);
+
+    if let Some(part) = part {
+        command.args(["--part", &part.number().to_string()]);
+    }
+
+    if let Some(input) = input {
+        command.arg("--input").arg(input);
+    }
+
+    let output = command.stdin(Stdio::inherit()).output()?;
+
+    match serde_json::from_slice::<DayReport>(&output.stdout) {
+        Ok(report) => Ok(report),
+        Err(_) if !output.status.success() => Ok(DayReport::error(
+            day.number,
+            ErrorKind::Crash,
+            String::from_utf8_lossy(&output.stderr).trim(),
+        )),
+        Err(error) => Err(RunnerError::InvalidDayOutput { day: *day, error }),
+    }
+}
+
+/// Generates a random input with the already built binary of a day.
+pub fn generate_input(
+    workspace: &Workspace,
+    day: &Day,
+    size: usize,
+    seed: u64,
+) -> Result<String, RunnerError> {
+    let output = Command::new(workspace.executable(day))
+        .args(["--generate", &size.to_string(), "--seed", &seed.to_string()])
+        .output()?;
+
+    if !output.status.success() {
+        return Err(RunnerError::DayFailed {
+            day: *day,
+            stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayBenchReport {
+    pub day: u8,
+    #[serde(flatten)]
+    pub report: BenchReport,
+}
+
+/// Benchmarks the already built binary of a day on its default input.
+pub fn bench_day(
+    workspace: &Workspace,
+    day: &Day,
+    config: &BenchConfig,
+) -> Result<DayBenchReport, RunnerError> {
+    let mut command = Command::new(workspace.executable(day));
+    command.args([
+        "--bench",
+        "--format",
+        "json",
+        "--warmup",
+        &config.warmup.to_string(),
+        "--samples",
+        &config.samples.to_string(),
+    ]);
+
+    let output = command.stdin(Stdio::inherit()).output()?;
+
+    if !output.status.success() {
+        let message = match serde_json::from_slice::<DayReport>(&output.stdout) {
+            Ok(DayReport {
+                outcome: DayOutcome::Error(error),
+                ..
+            }) => error.to_string(),
+            _ => String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        };
+
+        return Err(RunnerError::DayFailed {
+            day: *day,
+            stderr: message,
+        });
+    }
+
+    let report = serde_json::from_slice(&output.stdout)
+        .map_err(|error| RunnerError::InvalidDayOutput { day: *day, error })?;
+
+    Ok(DayBenchReport {
+        day: day.number,
+        report,
+    })
+}
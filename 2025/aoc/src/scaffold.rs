@@ -0,0 +1,293 @@
+use std::{fs, path::Path};
+
+use crate::{
+    RunnerError,
+    workspace::{Day, Repository, Workspace},
+};
+
+const TEMPLATE_PACKAGE: &str = "day-xx";
+const TEMPLATE_LIBRARY: &str = "day_xx";
+const TEMPLATE_STRUCT: &str = "DayXX";
+const TEMPLATE_DAY: &str = "const DAY: u8 = 0;";
+
+/// The files of a workspace that set up the toolchain and the development environment.
+const SETUP_FILES: [&str; 6] = [
+    ".envrc",
+    ".gitignore",
+    "bacon.toml",
+    "flake.lock",
+    "flake.nix",
+    "rust-toolchain.toml",
+];
+
+const ANSWER_REGISTRY_HEADER: &str = "\
+# The accepted answers to the real puzzle input (input.txt) of every day.
+# `aoc verify` compares the current solutions against them.
+";
+
+/// Creates the crate of a new day from the `template` crate and adds it to the workspace members.
+pub fn new_day(workspace: &Workspace, day: &Day) -> Result<(), RunnerError> {
+    let day_dir = workspace.root().join(day.package());
+
+    if day_dir.exists() {
+        return Err(RunnerError::DayAlreadyExists(*day));
+    }
+
+    let manifest_path = workspace.root().join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let manifest =
+        add_workspace_member(&manifest, &day.package()).ok_or(RunnerError::NoWorkspaceMembers)?;
+
+    copy_dir(&workspace.root().join("template"), &day_dir, &|content| {
+        content
+            .replace(TEMPLATE_PACKAGE, &day.package())
+            .replace(TEMPLATE_LIBRARY, &day.package().replace('-', "_"))
+            .replace(TEMPLATE_STRUCT, &format!("Day{:02}", day.number))
+            .replace(TEMPLATE_DAY, &format!("const DAY: u8 = {};", day.number))
+    })?;
+    fs::write(day_dir.join("input.txt"), "")?;
+    fs::write(manifest_path, manifest)?;
+
+    Ok(())
+}
+
+/// Creates the workspace of a new year next to the given one, with the same setup files and `template` crate.
+///
+/// The new workspace has no days yet and depends on the shared crates of the given workspace, e.g. `aoc-core`.
+pub fn new_year(
+    repository: &Repository,
+    workspace: &Workspace,
+    year: u16,
+) -> Result<Workspace, RunnerError> {
+    let year_dir = repository.year_dir(year);
+
+    if year_dir.exists() {
+        return Err(RunnerError::YearAlreadyExists(year));
+    }
+
+    let workspace_name = workspace
+        .root()
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or(RunnerError::UnknownYear)?;
+    let manifest = fs::read_to_string(workspace.root().join("Cargo.toml"))?;
+    let manifest = new_year_manifest(&manifest, year, &format!("../{workspace_name}"))
+        .ok_or(RunnerError::NoWorkspaceMembers)?;
+
+    fs::create_dir_all(&year_dir)?;
+
+    for file in SETUP_FILES {
+        let from = workspace.root().join(file);
+
+        if from.is_file() {
+            fs::copy(from, year_dir.join(file))?;
+        }
+    }
+
+    copy_dir(
+        &workspace.root().join("template"),
+        &year_dir.join("template"),
+        &|content| content,
+    )?;
+    fs::write(year_dir.join("answers.toml"), ANSWER_REGISTRY_HEADER)?;
+    fs::write(year_dir.join("Cargo.toml"), manifest)?;
+
+    Ok(Workspace::new(year_dir))
+}
+
+/// Copies a directory without its `target` directories, passing the content of every file through `transform`.
+fn copy_dir(
+    from: &Path,
+    to: &Path,
+    transform: &dyn Fn(String) -> String,
+) -> Result<(), RunnerError> {
+    fs::create_dir_all(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let target = to.join(&name);
+
+        if entry.file_type()?.is_dir() {
+            if name != "target" {
+                copy_dir(&entry.path(), &target, transform)?;
+            }
+        } else {
+            fs::write(target, transform(fs::read_to_string(entry.path())?))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Derives the manifest of a new year from the manifest of an existing workspace:
+/// `template` is the only member, the description names the new year
+/// and the path dependencies, e.g. `aoc-core`, are taken from the existing workspace.
+fn new_year_manifest(manifest: &str, year: u16, workspace_path: &str) -> Option<String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let members_start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))?;
+    let members_end = members_start
+        + lines[members_start..]
+            .iter()
+            .position(|line| line.trim() == "]")?;
+
+    let mut result = Vec::new();
+
+    for (index, line) in lines.iter().enumerate() {
+        if index == members_start {
+            result.extend(["members = [".to_string(), "    \"template\",".to_string()]);
+        } else if index > members_start && index < members_end {
+            continue;
+        } else if line.starts_with("exclude = ") {
+            // the excluded crates, e.g. the fuzz targets, stay with the old year
+            continue;
+        } else if line.starts_with("description = ") {
+            result.push(format!("description = \"Advent of Code {year}\""));
+        } else {
+            result.push(line.replace("path = \"", &format!("path = \"{workspace_path}/")));
+        }
+    }
+
+    Some(result.join("\n") + "\n")
+}
+
+/// Inserts the member into the `members` list of a workspace manifest,
+/// after the last member that sorts before it.
+fn add_workspace_member(manifest: &str, member: &str) -> Option<String> {
+    let lines = manifest.lines().collect::<Vec<_>>();
+
+    let members_start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with("members = ["))?;
+    let members_end = members_start
+        + lines[members_start..]
+            .iter()
+            .position(|line| line.trim() == "]")?;
+
+    let insert_at = lines[members_start + 1..members_end]
+        .iter()
+        .rposition(|line| {
+            let existing = line.trim().trim_end_matches(',').trim_matches('"');
+            existing.starts_with("day-") && existing < member
+        })
+        .map(|index| members_start + 1 + index + 1)
+        .unwrap_or(members_end);
+
+    let member_line = format!("    \"{member}\",");
+
+    let mut result = lines[..insert_at].to_vec();
+    result.push(&member_line);
+    result.extend(&lines[insert_at..]);
+
+    Some(result.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"template\",\n    \"day-01\",\n    \"day-03\",\n]\n\n[workspace.package]\n";
+
+    #[test]
+    fn add_workspace_member_at_the_end_works() {
+        let manifest = add_workspace_member(MANIFEST, "day-04").unwrap();
+        assert!(manifest.contains("    \"day-03\",\n    \"day-04\",\n]\n"));
+    }
+
+    #[test]
+    fn add_workspace_member_in_between_works() {
+        let manifest = add_workspace_member(MANIFEST, "day-02").unwrap();
+        assert!(manifest.contains("    \"day-01\",\n    \"day-02\",\n    \"day-03\",\n"));
+        assert!(manifest.ends_with("[workspace.package]\n"));
+    }
+
+    #[test]
+    fn add_workspace_member_without_members_fails() {
+        assert_eq!(None, add_workspace_member("[workspace]\n", "day-01"));
+    }
+
+    #[test]
+    fn new_year_manifest_works() {
+        let manifest = "[workspace]\nresolver = \"3\"\nmembers = [\n    \"aoc\",\n    \"day-01\",\n]\nexclude = [\"fuzz\"]\n\n[workspace.package]\ndescription = \"Advent of Code 2025\"\n\n[workspace.dependencies]\naoc-core = { path = \"aoc-core\" }\naoc-grid = { path = \"aoc-grid\" }\nclap = \"4.5\"\n";
+
+        assert_eq!(
+            "[workspace]\nresolver = \"3\"\nmembers = [\n    \"template\",\n]\n\n[workspace.package]\ndescription = \"Advent of Code 2026\"\n\n[workspace.dependencies]\naoc-core = { path = \"../2025/aoc-core\" }\naoc-grid = { path = \"../2025/aoc-grid\" }\nclap = \"4.5\"\n",
+            new_year_manifest(manifest, 2026, "../2025").unwrap()
+        );
+        assert_eq!(None, new_year_manifest("[workspace]\n", 2026, "../2025"));
+    }
+
+    #[test]
+    fn new_year_works() {
+        let root = std::env::temp_dir().join(format!("aoc-new-year-{}", std::process::id()));
+        let current = root.join("2025");
+        fs::create_dir_all(current.join("template/src")).unwrap();
+        fs::write(current.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(current.join("bacon.toml"), "default_job = \"run\"\n").unwrap();
+        fs::write(current.join("template/src/main.rs"), "struct DayXX;\n").unwrap();
+
+        let repository = Repository::new(&root);
+        let workspace = new_year(&repository, &Workspace::new(&current), 2026).unwrap();
+        let second_attempt = new_year(&repository, &Workspace::new(&current), 2026);
+
+        let manifest = fs::read_to_string(root.join("2026/Cargo.toml")).unwrap();
+        let bacon = fs::read_to_string(root.join("2026/bacon.toml")).unwrap();
+        let main = fs::read_to_string(root.join("2026/template/src/main.rs")).unwrap();
+        let days = workspace.days().unwrap();
+        let years = repository.years().unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(manifest.contains("members = [\n    \"template\",\n]\n"));
+        assert_eq!("default_job = \"run\"\n", bacon);
+        assert_eq!("struct DayXX;\n", main);
+        assert!(days.is_empty());
+        assert_eq!(vec![2025, 2026], years);
+        assert!(matches!(
+            second_attempt,
+            Err(RunnerError::YearAlreadyExists(2026))
+        ));
+    }
+
+    #[test]
+    fn new_day_works() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let template = root.join("template");
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), MANIFEST).unwrap();
+        fs::write(template.join("Cargo.toml"), "name = \"day-xx\"\n").unwrap();
+        fs::write(
+            template.join("src/main.rs"),
+            "use day_xx::DayXX;\nstruct DayXX;\nconst DAY: u8 = 0;\n",
+        )
+        .unwrap();
+
+        let workspace = Workspace::new(&root);
+        let day = Day { number: 7 };
+
+        new_day(&workspace, &day).unwrap();
+        let second_attempt = new_day(&workspace, &day);
+
+        let manifest = fs::read_to_string(root.join("day-07/Cargo.toml")).unwrap();
+        let main = fs::read_to_string(root.join("day-07/src/main.rs")).unwrap();
+        let input = fs::read_to_string(root.join("day-07/input.txt")).unwrap();
+        let members = workspace.days().unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!("name = \"day-07\"\n", manifest);
+        assert_eq!(
+            "use day_07::Day07;\nstruct Day07;\nconst DAY: u8 = 7;\n",
+            main
+        );
+        assert_eq!("", input);
+        assert_eq!(vec![day], members);
+        assert!(matches!(
+            second_attempt,
+            Err(RunnerError::DayAlreadyExists(Day { number: 7 }))
+        ));
+    }
+}
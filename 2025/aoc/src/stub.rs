@@ -0,0 +1,69 @@
+//! A minimal HTTP server that answers requests with canned responses, for testing the client.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    thread::{self, JoinHandle},
+};
+
+pub struct StubServer {
+    pub base_url: String,
+    handle: JoinHandle<Vec<String>>,
+}
+
+impl StubServer {
+    /// Serves one connection per response, in order, then stops.
+    pub fn serve(responses: Vec<(u16, String)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("stub server should bind");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().expect("client should connect");
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+
+                    request.push_str(&line);
+
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8_lossy(&request_body));
+                requests.push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+
+            requests
+        });
+
+        Self { base_url, handle }
+    }
+
+    /// Waits until all responses were served and returns the raw requests.
+    pub fn requests(self) -> Vec<String> {
+        self.handle.join().expect("stub server should not panic")
+    }
+}
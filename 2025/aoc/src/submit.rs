@@ -0,0 +1,318 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// What the website answered to a submitted guess.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", tag = "outcome")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait { seconds: u64 },
+    AlreadySolved,
+    Unknown { message: String },
+}
+
+impl Outcome {
+    /// Recognizes the outcome from the HTML page returned for a submission.
+    pub fn from_response(html: &str) -> Self {
+        let message = article_text(html);
+
+        if message.contains("That's the right answer") {
+            Outcome::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Outcome::TooHigh
+            } else if message.contains("too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Outcome::Wait {
+                seconds: wait_seconds(&message).unwrap_or(60),
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown { message }
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer"),
+            Outcome::TooHigh => write!(f, "wrong, the answer is too high"),
+            Outcome::TooLow => write!(f, "wrong, the answer is too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait { seconds } => write!(f, "answered too recently, wait {seconds}s"),
+            Outcome::AlreadySolved => write!(f, "this part is already solved or not unlocked yet"),
+            Outcome::Unknown { message } => write!(f, "unexpected response: {message}"),
+        }
+    }
+}
+
+/// A single guess and its outcome, as stored in the local submission history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub date: String,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong,
+    NotBelowTooHigh(String),
+    NotAboveTooLow(String),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(f, "this part is already solved with {answer}")
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already submitted and is wrong"),
+            Refusal::NotBelowTooHigh(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which is too high"
+                )
+            }
+            Refusal::NotAboveTooLow(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which is too low"
+                )
+            }
+        }
+    }
+}
+
+/// Checks a guess against the earlier submissions of the same part before sending it.
+pub fn check_guess(
+    history: &[Submission],
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<(), Refusal> {
+    let earlier = history
+        .iter()
+        .filter(|submission| {
+            submission.year == year && submission.day == day && submission.part == part
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(correct) = earlier
+        .iter()
+        .find(|submission| submission.outcome == Outcome::Correct)
+    {
+        return Err(Refusal::AlreadyCorrect(correct.answer.clone()));
+    }
+
+    if earlier
+        .iter()
+        .any(|submission| submission.outcome.is_wrong() && submission.answer == answer)
+    {
+        return Err(Refusal::KnownWrong);
+    }
+
+    let Ok(guess) = answer.parse::<i128>() else {
+        return Ok(());
+    };
+
+    let bound = |outcome: Outcome| {
+        earlier
+            .iter()
+            .filter(move |submission| submission.outcome == outcome)
+            .filter_map(|submission| submission.answer.parse::<i128>().ok())
+    };
+
+    if let Some(lowest_too_high) = bound(Outcome::TooHigh).min()
+        && guess >= lowest_too_high
+    {
+        return Err(Refusal::NotBelowTooHigh(lowest_too_high.to_string()));
+    }
+
+    if let Some(highest_too_low) = bound(Outcome::TooLow).max()
+        && guess <= highest_too_low
+    {
+        return Err(Refusal::NotAboveTooLow(highest_too_low.to_string()));
+    }
+
+    Ok(())
+}
+
+/// The text of the `<article>` element, without any tags.
+fn article_text(html: &str) -> String {
+    // the rest after `<article` still belongs to the opening tag
+    let (article, mut in_tag) = match html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (article, true),
+        None => (html, false),
+    };
+
+    let mut text = String::with_capacity(article.len());
+
+    for char in article.chars() {
+        match char {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(char),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses durations like `You have 1m 23s left to wait`.
+fn wait_seconds(message: &str) -> Option<u64> {
+    let (_, rest) = message.split_once("You have ")?;
+    let (duration, _) = rest.split_once(" left to wait")?;
+
+    duration
+        .split_whitespace()
+        .map(|component| {
+            if let Some(minutes) = component.strip_suffix('m') {
+                minutes.parse::<u64>().ok().map(|minutes| minutes * 60)
+            } else {
+                component.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, answer: &str, outcome: Outcome) -> Submission {
+        Submission {
+            year: 2025,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            date: "2025-12-01T05:00:00Z".to_string(),
+            outcome,
+        }
+    }
+
+    #[test]
+    fn outcome_from_response_works() {
+        let page = |message: &str| {
+            format!("<html><main><article><p>{message}</p></article></main></html>")
+        };
+
+        assert_eq!(
+            Outcome::Correct,
+            Outcome::from_response(&page(
+                "That's the right answer! You are <em>one gold star</em> closer."
+            ))
+        );
+        assert_eq!(
+            Outcome::TooHigh,
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too high."
+            ))
+        );
+        assert_eq!(
+            Outcome::TooLow,
+            Outcome::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            ))
+        );
+        assert_eq!(
+            Outcome::Wrong,
+            Outcome::from_response(&page("That's not the right answer."))
+        );
+        assert_eq!(
+            Outcome::Wait { seconds: 83 },
+            Outcome::from_response(&page(
+                "You gave an answer too recently. You have 1m 23s left to wait."
+            ))
+        );
+        assert_eq!(
+            Outcome::AlreadySolved,
+            Outcome::from_response(&page("You don't seem to be solving the right level."))
+        );
+        assert_eq!(
+            Outcome::Unknown {
+                message: "Something else.".to_string()
+            },
+            Outcome::from_response(&page("Something else."))
+        );
+        assert_eq!(
+            Outcome::Unknown {
+                message: "Puzzle inputs differ by user.".to_string()
+            },
+            Outcome::from_response("Puzzle inputs differ by user.")
+        );
+    }
+
+    #[test]
+    fn submission_serialization_works() {
+        let submission = submission(2, "42", Outcome::Wait { seconds: 30 });
+        let json = serde_json::to_string(&submission).unwrap();
+        assert!(json.contains(r#""outcome":"wait","seconds":30"#));
+        assert_eq!(submission, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn known_wrong_guess_is_refused() {
+        let history = vec![submission(1, "42", Outcome::Wrong)];
+        assert_eq!(
+            Err(Refusal::KnownWrong),
+            check_guess(&history, 2025, 1, 1, "42")
+        );
+        assert_eq!(Ok(()), check_guess(&history, 2025, 1, 1, "43"));
+        assert_eq!(Ok(()), check_guess(&history, 2025, 1, 2, "42"));
+    }
+
+    #[test]
+    fn guess_outside_bounds_is_refused() {
+        let history = vec![
+            submission(1, "100", Outcome::TooHigh),
+            submission(1, "200", Outcome::TooHigh),
+            submission(1, "10", Outcome::TooLow),
+            submission(1, "50", Outcome::Wait { seconds: 60 }),
+        ];
+
+        assert_eq!(
+            Err(Refusal::NotBelowTooHigh("100".to_string())),
+            check_guess(&history, 2025, 1, 1, "150")
+        );
+        assert_eq!(
+            Err(Refusal::NotAboveTooLow("10".to_string())),
+            check_guess(&history, 2025, 1, 1, "-3")
+        );
+        assert_eq!(Ok(()), check_guess(&history, 2025, 1, 1, "50"));
+        assert_eq!(Ok(()), check_guess(&history, 2025, 1, 1, "ABC"));
+    }
+
+    #[test]
+    fn solved_part_is_refused() {
+        let history = vec![submission(1, "7", Outcome::Correct)];
+        assert_eq!(
+            Err(Refusal::AlreadyCorrect("7".to_string())),
+            check_guess(&history, 2025, 1, 1, "8")
+        );
+    }
+}
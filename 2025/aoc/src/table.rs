@@ -0,0 +1,80 @@
+use std::fmt::Display;
+
+/// A plain text table with left aligned columns, used for every summary the runner prints.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new<const N: usize>(headers: [&str; N]) -> Self {
+        Self {
+            headers: headers.iter().map(|header| header.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| header.len()).collect();
+
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        widths
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let widths = self.column_widths();
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: &[String]| {
+            let cells = widths
+                .iter()
+                .enumerate()
+                .map(|(index, width)| {
+                    let cell = row.get(index).map(String::as_str).unwrap_or("");
+                    format!("{cell:<width$}")
+                })
+                .collect::<Vec<_>>();
+            writeln!(f, " {}", cells.join(" | ").trim_end())
+        };
+
+        write_row(f, &self.headers)?;
+
+        let separator = widths
+            .iter()
+            .map(|width| "-".repeat(width + 2))
+            .collect::<Vec<_>>()
+            .join("+");
+        writeln!(f, "{separator}")?;
+
+        for row in self.rows.iter() {
+            write_row(f, row)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_rendering_works() {
+        let mut table = Table::new(["day", "answer"]);
+        table.add_row(vec!["1".to_string(), "1086".to_string()]);
+        table.add_row(vec!["12".to_string(), "7".to_string()]);
+
+        let expected = " day | answer\n-----+--------\n 1   | 1086\n 12  | 7\n";
+        assert_eq!(expected, table.to_string());
+    }
+}
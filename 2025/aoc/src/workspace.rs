@@ -0,0 +1,253 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{RunnerError, git};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Day {
+    pub number: u8,
+}
+
+impl Day {
+    pub fn package(&self) -> String {
+        format!("day-{:02}", self.number)
+    }
+
+    /// Recognizes the directory and package names of days, e.g. `day-04`.
+    pub fn from_package(package: &str) -> Option<Day> {
+        let number = package.strip_prefix("day-")?;
+
+        if number.len() != 2 {
+            return None;
+        }
+
+        number.parse().ok().map(|number| Day { number })
+    }
+}
+
+/// The repository that holds one cargo workspace per year, e.g. `2025/`.
+pub struct Repository {
+    root: PathBuf,
+}
+
+impl Repository {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The repository around the workspace this runner was built in.
+    pub fn locate() -> Self {
+        let workspace = Workspace::locate();
+        Self::new(workspace.root().parent().unwrap_or(workspace.root()))
+    }
+
+    /// The directory of the workspace of a year, which may not exist yet.
+    pub fn year_dir(&self, year: u16) -> PathBuf {
+        self.root.join(year.to_string())
+    }
+
+    /// Every year with a workspace, in ascending order.
+    pub fn years(&self) -> Result<Vec<u16>, RunnerError> {
+        let mut years = Vec::new();
+
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+
+            if let Some(year) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse().ok())
+                && entry.path().join("Cargo.toml").is_file()
+            {
+                years.push(year);
+            }
+        }
+
+        years.sort();
+
+        Ok(years)
+    }
+
+    pub fn workspace(&self, year: u16) -> Result<Workspace, RunnerError> {
+        let root = self.year_dir(year);
+
+        if root.join("Cargo.toml").is_file() {
+            Ok(Workspace::new(root))
+        } else {
+            Err(RunnerError::YearNotFound {
+                year,
+                years: self.years()?,
+            })
+        }
+    }
+}
+
+/// The cargo workspace that holds the day crates of a year.
+pub struct Workspace {
+    root: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The workspace this runner was built in.
+    pub fn locate() -> Self {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        Self::new(manifest_dir.parent().unwrap_or(manifest_dir))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The year of the puzzles, taken from the name of the workspace directory.
+    pub fn year(&self) -> Option<u16> {
+        self.root.file_name()?.to_str()?.parse().ok()
+    }
+
+    pub fn input(&self, day: &Day) -> PathBuf {
+        self.root.join(day.package()).join("input.txt")
+    }
+
+    /// The file with the checked-in answers of every day.
+    pub fn answer_registry(&self) -> PathBuf {
+        self.root.join("answers.toml")
+    }
+
+    /// The local store of benchmark results, it is not checked in.
+    pub fn bench_history(&self) -> PathBuf {
+        self.root.join(".aoc").join("bench-history.jsonl")
+    }
+
+    /// The local log of every answer submitted to the website, it is not checked in.
+    pub fn submission_history(&self) -> PathBuf {
+        self.root.join(".aoc").join("submissions.jsonl")
+    }
+
+    pub fn git_commit(&self) -> String {
+        git::head_commit(&self.root).unwrap_or_else(|| "unknown".to_string())
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        git::is_dirty(&self.root)
+    }
+
+    /// Resolves a revision to a full commit hash, or returns it unchanged if git does not know it.
+    pub fn resolve_commit(&self, revision: &str) -> String {
+        git::resolve_commit(&self.root, revision).unwrap_or_else(|| revision.to_string())
+    }
+
+    /// Every `day-NN` crate of the workspace, ordered by day.
+    pub fn days(&self) -> Result<Vec<Day>, RunnerError> {
+        let mut days = Vec::new();
+
+        for entry in fs::read_dir(&self.root)? {
+            let entry = entry?;
+            let name = entry.file_name();
+
+            if let Some(day) = name.to_str().and_then(Day::from_package)
+                && entry.path().join("Cargo.toml").is_file()
+            {
+                days.push(day);
+            }
+        }
+
+        days.sort();
+
+        Ok(days)
+    }
+
+    /// The given day or every day if none is given.
+    pub fn select_days(&self, number: Option<u8>) -> Result<Vec<Day>, RunnerError> {
+        match number {
+            Some(number) => Ok(vec![self.day(number)?]),
+            None => self.days(),
+        }
+    }
+
+    pub fn day(&self, number: u8) -> Result<Day, RunnerError> {
+        self.days()?
+            .into_iter()
+            .find(|day| day.number == number)
+            .ok_or(RunnerError::DayNotFound(number))
+    }
+
+    /// Builds the release binaries of the days with the given features, e.g. `aoc-core/alloc-stats`.
+    pub fn build(&self, days: &[Day], features: &[&str]) -> Result<(), RunnerError> {
+        let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+        command
+            .current_dir(&self.root)
+            .args(["build", "--release", "--quiet"]);
+
+        for day in days {
+            command.args(["--package", &day.package()]);
+        }
+
+        for feature in features {
+            command.args(["--features", feature]);
+        }
+
+        if command.status()?.success() {
+            Ok(())
+        } else {
+            Err(RunnerError::BuildFailed)
+        }
+    }
+
+    pub fn executable(&self, day: &Day) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| self.root.join("target"));
+
+        target_dir
+            .join("release")
+            .join(format!("{}{}", day.package(), env::consts::EXE_SUFFIX))
+    }
+}
+
+impl From<io::Error> for RunnerError {
+    fn from(error: io::Error) -> Self {
+        RunnerError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_from_package_works() {
+        assert_eq!(Some(Day { number: 4 }), Day::from_package("day-04"));
+        assert_eq!(Some(Day { number: 25 }), Day::from_package("day-25"));
+        assert_eq!(None, Day::from_package("day-4"));
+        assert_eq!(None, Day::from_package("day-xx"));
+        assert_eq!(None, Day::from_package("template"));
+    }
+
+    #[test]
+    fn years_of_this_repository_are_found() {
+        let repository = Repository::locate();
+        let years = repository.years().expect("repository should be readable");
+
+        assert!(years.contains(&2025));
+        assert!(repository.workspace(2025).is_ok());
+        assert!(matches!(
+            repository.workspace(1999),
+            Err(RunnerError::YearNotFound { year: 1999, .. })
+        ));
+    }
+
+    #[test]
+    fn days_of_this_workspace_are_found() {
+        let days = Workspace::locate()
+            .days()
+            .expect("workspace should be readable");
+        let first_days = (1..=6).map(|number| Day { number }).collect::<Vec<_>>();
+        assert_eq!(first_days, days[..6]);
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(rotations: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(find_password(rotations)?.0.into())
+    }
+
+    fn part_two(rotations: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(find_password(rotations)?.1.into())
+    }
+}
+
+impl Generator for Day01 {
+    /// `size` rotations of up to 999 clicks.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let direction = rng.choose(&['L', 'R']);
+                format!("{direction}{}\n", rng.range(1..=999))
+            })
+            .collect()
+    }
+}
+
+const NUMBER_OF_DIAL_POSITIONS: i64 = 100;
+
+/// Parses every rotation into a signed amount: negative for `L`, positive for `R`.
+pub fn parse_input(input: &str) -> Result<Vec<i64>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let Some((rotation_direction, rotation_amount)) = line.split_at_checked(1) else {
+                return Err(ParseError::at(input, line, "expected a rotation"));
+            };
+
+            let rotation_multiplier = match rotation_direction {
+                "L" => -1,
+                "R" => 1,
+                _ => {
+                    return Err(ParseError::at(
+                        input,
+                        rotation_direction,
+                        "expected `L` or `R`",
+                    ));
+                }
+            };
+
+            let rotation_amount = rotation_amount
+                .parse::<u32>()
+                .map_err(|_| ParseError::at(input, rotation_amount, "expected an amount"))?;
+
+            Ok(rotation_multiplier * i64::from(rotation_amount))
+        })
+        .collect()
+}
+
+pub fn find_password(rotations: &[i64]) -> Result<(i64, i64), SolveError> {
+    let mut part_one_answer = 0;
+    let mut part_two_answer: i64 = 0;
+
+    let mut dial_position = 50;
+
+    for rotation in rotations {
+        let rotation_amount = rotation.checked_abs().ok_or(SolveError::Overflow)?;
+
+        let passes_of_zero = if *rotation > 0 {
+            rotation_amount / NUMBER_OF_DIAL_POSITIONS
+                + (dial_position + rotation_amount % NUMBER_OF_DIAL_POSITIONS)
+                    / NUMBER_OF_DIAL_POSITIONS
+        } else if dial_position <= rotation_amount {
+            let additional = if dial_position == 0 { 0 } else { 1 };
+            (rotation_amount - dial_position) / NUMBER_OF_DIAL_POSITIONS + additional
+        } else {
+            0
+        };
+
+        part_two_answer = part_two_answer
+            .checked_add(passes_of_zero)
+            .ok_or(SolveError::Overflow)?;
+
+        // reducing the rotation first keeps the sum far away from overflowing
+        dial_position += rotation.rem_euclid(NUMBER_OF_DIAL_POSITIONS);
+        dial_position = dial_position.rem_euclid(NUMBER_OF_DIAL_POSITIONS);
+
+        assert!(dial_position >= 0);
+
+        if dial_position == 0 {
+            part_one_answer += 1;
+        }
+    }
+
+    Ok((part_one_answer, part_two_answer))
+}
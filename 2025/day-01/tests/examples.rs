@@ -0,0 +1,35 @@
+use aoc_core::{ParseError, SolveError};
+use day_01::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day01);
+aoc_core::generator_tests!(Day01);
+
+#[test]
+fn parse_input_works() {
+    let rotations = parse_input(INPUT).expect("rotations should be valid");
+    let expected = vec![-68, -30, 48, -5, 60, -55, -1, -99, 14, -82];
+    assert_eq!(expected, rotations);
+}
+
+#[test]
+fn find_password_works() {
+    let rotations = parse_input(INPUT).expect("rotations should be valid");
+    assert_eq!(Ok((3, 6)), find_password(&rotations));
+}
+
+#[test]
+fn parse_input_reports_invalid_rotations() {
+    let error = parse_input("L68\nX30\nR").unwrap_err();
+    assert_eq!(ParseError::new(2, 1, "X", "expected `L` or `R`"), error);
+
+    let error = parse_input("L68\nR").unwrap_err();
+    assert_eq!(ParseError::new(2, 2, "", "expected an amount"), error);
+}
+
+#[test]
+fn find_password_reports_overflows() {
+    assert_eq!(Err(SolveError::Overflow), find_password(&[i64::MIN]));
+    assert_eq!(Ok((0, 92233720368547758)), find_password(&[i64::MAX]));
+}
@@ -0,0 +1,32 @@
+use day_01::*;
+use proptest::prelude::*;
+
+/// Turns the dial one click at a time and counts how often it ends on or passes zero.
+fn find_password_by_clicking(rotations: &[i64]) -> (i64, i64) {
+    let mut ends_on_zero = 0;
+    let mut clicks_on_zero = 0;
+    let mut dial_position = 50;
+
+    for rotation in rotations {
+        for _ in 0..rotation.unsigned_abs() {
+            dial_position = (dial_position + rotation.signum()).rem_euclid(100);
+
+            if dial_position == 0 {
+                clicks_on_zero += 1;
+            }
+        }
+
+        if dial_position == 0 {
+            ends_on_zero += 1;
+        }
+    }
+
+    (ends_on_zero, clicks_on_zero)
+}
+
+proptest! {
+    #[test]
+    fn find_password_matches_clicking(rotations in prop::collection::vec(-1000_i64..=1000, 0..50)) {
+        prop_assert_eq!(Ok(find_password_by_clicking(&rotations)), find_password(&rotations));
+    }
+}
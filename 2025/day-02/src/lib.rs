@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed = Vec<IdRange>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(ranges: &Self::Parsed) -> Result<Answer, SolveError> {
+        sum_of_ids(&find_invalid_ids(ranges, part_one_is_invalid_id)).map(Answer::from)
+    }
+
+    fn part_two(ranges: &Self::Parsed) -> Result<Answer, SolveError> {
+        sum_of_ids(&find_invalid_ids(ranges, part_two_is_invalid_id)).map(Answer::from)
+    }
+}
+
+impl Generator for Day02 {
+    /// `size` ranges of up to 1000 IDs with up to 10 digits.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let ranges = (0..size)
+            .map(|_| {
+                let start = rng.range(1..=9_999_999_999);
+                format!("{start}-{}", start + rng.below(1000))
+            })
+            .collect::<Vec<_>>();
+
+        ranges.join(",") + "\n"
+    }
+}
+
+pub type Id = u64;
+pub type IdRange = RangeInclusive<Id>;
+
+pub fn parse_input(input: &str) -> Result<Vec<IdRange>, ParseError> {
+    let parse_id = |id: &str| {
+        id.parse::<Id>()
+            .map_err(|_| ParseError::at(input, id, "expected a positive integer ID"))
+    };
+
+    input
+        .split(',')
+        .map(|range| {
+            let range = range.trim();
+
+            let Some((start, end)) = range.split_once('-') else {
+                return Err(ParseError::at(input, range, "expected a range `start-end`"));
+            };
+
+            Ok(IdRange::new(parse_id(start)?, parse_id(end)?))
+        })
+        .collect()
+}
+
+pub fn find_invalid_ids(id_ranges: &[IdRange], is_invalid: fn(&str) -> bool) -> Vec<Id> {
+    id_ranges
+        .iter()
+        .flat_map(|range| range.clone().map(|value| (value, value.to_string())))
+        .filter_map(|r| if is_invalid(&r.1) { Some(r.0) } else { None })
+        .collect()
+}
+
+pub fn sum_of_ids(ids: &[Id]) -> Result<Id, SolveError> {
+    ids.iter()
+        .try_fold(0, |sum: Id, id| sum.checked_add(*id))
+        .ok_or(SolveError::Overflow)
+}
+
+pub fn part_one_is_invalid_id(id: &str) -> bool {
+    let (left, right) = id.split_at(id.len() / 2);
+    left == right
+}
+
+pub fn part_two_is_invalid_id(id: &str) -> bool {
+    let max_substr_len = id.len() / 2;
+
+    'outer: for substr_len in (1..=max_substr_len).filter(|l| id.len().is_multiple_of(*l)) {
+        let (substr_to_check, rest) = id.split_at(substr_len);
+
+        for window_into_rest in rest.chars().collect::<Vec<_>>().chunks(substr_len) {
+            for (char_of_substr_to_check, char_of_rest) in
+                substr_to_check.chars().zip(window_into_rest)
+            {
+                if char_of_substr_to_check != *char_of_rest {
+                    continue 'outer;
+                }
+            }
+        }
+
+        return true;
+    }
+
+    false
+}
@@ -0,0 +1,66 @@
+use aoc_core::{ParseError, SolveError};
+use day_02::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day02);
+aoc_core::generator_tests!(Day02);
+
+#[test]
+fn parse_should_work() {
+    let ranges = parse_input(INPUT).expect("ranges should be valid");
+    let expected = vec![
+        11..=22,
+        95..=115,
+        998..=1012,
+        1188511880..=1188511890,
+        222220..=222224,
+        1698522..=1698528,
+        446443..=446449,
+        38593856..=38593862,
+        565653..=565659,
+        824824821..=824824827,
+        2121212118..=2121212124,
+    ];
+    assert_eq!(expected, ranges);
+}
+
+#[test]
+fn part_one_find_invalid_ids_works() {
+    let ranges = parse_input(INPUT).expect("ranges should be valid");
+    let invalid_ids = find_invalid_ids(&ranges, part_one_is_invalid_id);
+    let expected = vec![11, 22, 99, 1010, 1188511885, 222222, 446446, 38593859];
+    assert_eq!(expected, invalid_ids);
+}
+
+#[test]
+fn part_two_find_invalid_ids_works() {
+    let ranges = parse_input(INPUT).expect("ranges should be valid");
+    let invalid_ids = find_invalid_ids(&ranges, part_two_is_invalid_id);
+    let expected = vec![
+        11, 22, 99, 111, 999, 1010, 1188511885, 222222, 446446, 38593859, 565656, 824824824,
+        2121212121,
+    ];
+    assert_eq!(expected, invalid_ids);
+}
+
+#[test]
+fn parse_input_reports_invalid_ranges() {
+    let error = parse_input("11-22,95115").unwrap_err();
+    assert_eq!(
+        ParseError::new(1, 7, "95115", "expected a range `start-end`"),
+        error
+    );
+
+    let error = parse_input("11-22,\n95-x15\n").unwrap_err();
+    assert_eq!(
+        ParseError::new(2, 4, "x15", "expected a positive integer ID"),
+        error
+    );
+}
+
+#[test]
+fn sum_of_ids_reports_overflows() {
+    assert_eq!(Ok(33), sum_of_ids(&[11, 22]));
+    assert_eq!(Err(SolveError::Overflow), sum_of_ids(&[Id::MAX, 1]));
+}
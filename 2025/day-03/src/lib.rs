@@ -0,0 +1,101 @@
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(battery_banks: &Self::Parsed) -> Result<Answer, SolveError> {
+        max_joltage(battery_banks, 2).map(Answer::from)
+    }
+
+    fn part_two(battery_banks: &Self::Parsed) -> Result<Answer, SolveError> {
+        max_joltage(battery_banks, 12).map(Answer::from)
+    }
+}
+
+impl Generator for Day03 {
+    /// `size` banks of 100 batteries.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let bank = (0..100)
+                    .map(|_| char::from(b'0' + rng.range(1..=9) as u8))
+                    .collect::<String>();
+                bank + "\n"
+            })
+            .collect()
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.char_indices()
+                .map(|(index, char)| {
+                    char.to_digit(10).map(u64::from).ok_or_else(|| {
+                        let char = &line[index..index + char.len_utf8()];
+                        ParseError::at(input, char, "expected a joltage digit")
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn max_joltage(
+    banks: &[Vec<u64>],
+    num_of_turned_on_batteries: usize,
+) -> Result<u64, SolveError> {
+    banks.iter().try_fold(0_u64, |sum, bank| {
+        let jolts = find_max_jolts_of_battery_bank(bank, num_of_turned_on_batteries)?;
+        sum.checked_add(jolts).ok_or(SolveError::Overflow)
+    })
+}
+
+pub fn find_max_jolts_of_battery_bank(
+    bank: &[u64],
+    num_of_turned_on_batteries: usize,
+) -> Result<u64, SolveError> {
+    if bank.len() < num_of_turned_on_batteries {
+        return Err(SolveError::Unsolvable(format!(
+            "a bank of {} batteries cannot turn on {num_of_turned_on_batteries} batteries",
+            bank.len()
+        )));
+    }
+
+    let mut start_index = 0;
+    let mut max_jolts: u64 = 0;
+
+    for n in (1..=num_of_turned_on_batteries).rev() {
+        let (max_index, max_num) = find_max_with_index(&bank[start_index..=(bank.len() - n)]);
+        start_index += max_index + 1;
+        max_jolts = max_jolts
+            .checked_mul(10)
+            .and_then(|jolts| jolts.checked_add(max_num))
+            .ok_or(SolveError::Overflow)?;
+    }
+
+    Ok(max_jolts)
+}
+
+fn find_max_with_index(nums: &[u64]) -> (usize, u64) {
+    let mut max_index = 0;
+    let mut max = nums[max_index];
+
+    for (index, num) in nums[1..].iter().enumerate() {
+        if *num > max {
+            max = *num;
+            max_index = index + 1;
+        }
+    }
+
+    (max_index, max)
+}
@@ -0,0 +1,66 @@
+use aoc_core::{ParseError, SolveError};
+use day_03::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day03);
+aoc_core::generator_tests!(Day03);
+
+#[test]
+fn parse_input_should_work() {
+    let battery_banks = parse_input(INPUT).expect("battery banks should be valid");
+    let expected = vec![
+        vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+        vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+        vec![2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+        vec![8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+    ];
+    assert_eq!(expected, battery_banks);
+}
+
+#[test]
+fn part_one_find_max_jolts_of_battery_bank_works() {
+    let battery_banks = parse_input(INPUT).expect("battery banks should be valid");
+    let expected = vec![98, 89, 78, 92];
+    let actual: Vec<u64> = battery_banks
+        .iter()
+        .map(|bank| find_max_jolts_of_battery_bank(bank, 2))
+        .collect::<Result<_, _>>()
+        .expect("banks should be long enough");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn part_two_find_max_jolts_of_battery_bank_works() {
+    let battery_banks = parse_input(INPUT).expect("battery banks should be valid");
+    let expected = vec![987654321111, 811111111119, 434234234278, 888911112111];
+    let actual: Vec<u64> = battery_banks
+        .iter()
+        .map(|bank| find_max_jolts_of_battery_bank(bank, 12))
+        .collect::<Result<_, _>>()
+        .expect("banks should be long enough");
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn parse_input_reports_invalid_joltages() {
+    let error = parse_input("987\n81x9\n").unwrap_err();
+    assert_eq!(
+        ParseError::new(2, 3, "x", "expected a joltage digit"),
+        error
+    );
+}
+
+#[test]
+fn find_max_jolts_of_battery_bank_reports_short_banks_and_overflows() {
+    let error = find_max_jolts_of_battery_bank(&[9, 8, 7], 12).unwrap_err();
+    let expected = "a bank of 3 batteries cannot turn on 12 batteries";
+    assert_eq!(SolveError::Unsolvable(expected.to_string()), error);
+
+    let bank = vec![9; 20];
+    assert_eq!(
+        Err(SolveError::Overflow),
+        find_max_jolts_of_battery_bank(&bank, 20)
+    );
+    assert_eq!(Ok(0), find_max_jolts_of_battery_bank(&[], 0));
+}
@@ -0,0 +1,35 @@
+use day_03::*;
+use proptest::prelude::*;
+
+/// Tries every way to turn on the batteries, i.e. every subsequence of the bank with the given length.
+fn find_max_jolts_of_every_subsequence(bank: &[u64], num_of_turned_on_batteries: usize) -> u64 {
+    (0_u32..1 << bank.len())
+        .filter(|turned_on| turned_on.count_ones() as usize == num_of_turned_on_batteries)
+        .map(|turned_on| {
+            bank.iter()
+                .enumerate()
+                .filter(|(index, _)| turned_on & (1 << index) != 0)
+                .fold(0, |jolts, (_, battery)| jolts * 10 + battery)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn bank_and_batteries() -> impl Strategy<Value = (Vec<u64>, usize)> {
+    prop::collection::vec(0_u64..=9, 1..=14).prop_flat_map(|bank| {
+        let len = bank.len();
+        (Just(bank), 0..=len)
+    })
+}
+
+proptest! {
+    #[test]
+    fn find_max_jolts_of_battery_bank_matches_every_subsequence(
+        (bank, num_of_turned_on_batteries) in bank_and_batteries()
+    ) {
+        prop_assert_eq!(
+            Ok(find_max_jolts_of_every_subsequence(&bank, num_of_turned_on_batteries)),
+            find_max_jolts_of_battery_bank(&bank, num_of_turned_on_batteries)
+        );
+    }
+}
@@ -1,137 +1,47 @@
-pub struct Grid {
-    cells: Vec<Cell>,
-    pub width: usize,
-    pub height: usize,
-}
+pub use aoc_grid::{Coordinate, CoordinateNotInGrid};
+
+pub type Grid = aoc_grid::Grid<Cell>;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Cell {
     Empty,
     PaperRoll,
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct Coordinate {
-    pub x: usize,
-    pub y: usize,
-}
-
-#[derive(Debug)]
-pub struct CoordinateNotInGrid;
-
-impl Grid {
-    pub fn iter<'a>(&'a self) -> GridIterator<'a> {
-        GridIterator {
-            index: 0,
-            grid: self,
-        }
-    }
-
-    pub fn at(&self, coord: &Coordinate) -> Option<&Cell> {
-        if coord.x >= self.width || coord.y >= self.height {
-            return None;
-        }
-
-        let cell_index = coord.x + self.width * coord.y;
+impl TryFrom<char> for Cell {
+    type Error = &'static str;
 
-        if cell_index >= self.cells.len() {
-            return None;
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Cell::PaperRoll),
+            '.' => Ok(Cell::Empty),
+            _ => Err("expected `@` or `.`"),
         }
-
-        self.cells.get(cell_index)
     }
-
-    pub fn set(&mut self, coord: &Coordinate, cell: Cell) -> Result<(), CoordinateNotInGrid> {
-        if coord.x >= self.width || coord.y >= self.height {
-            return Err(CoordinateNotInGrid);
-        }
-
-        let cell_index = coord.x + self.width * coord.y;
-
-        if cell_index >= self.cells.len() {
-            return Err(CoordinateNotInGrid);
-        }
-
-        self.cells[cell_index] = cell;
-
-        Ok(())
-    }
-}
-
-#[derive(Debug)]
-pub enum GridConstructionError {
-    ValueIsNotOfRectangularShape,
-    DimensionsCannotBeZero,
 }
 
-impl TryFrom<&str> for Grid {
-    type Error = GridConstructionError;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let lines = value.lines().collect::<Vec<_>>();
-        let height = lines.len();
-
-        let cells: Vec<Cell> = lines
-            .iter()
-            .flat_map(|line| {
-                line.chars().map(|char| {
-                    if char == '@' {
-                        Cell::PaperRoll
-                    } else {
-                        Cell::Empty
-                    }
-                })
-            })
-            .collect();
-
-        if height == 0 {
-            return Err(GridConstructionError::DimensionsCannotBeZero);
-        }
-
-        let width = cells.len() / height;
-
-        if width == 0 {
-            return Err(GridConstructionError::DimensionsCannotBeZero);
-        }
-
-        if width * height != cells.len() {
-            return Err(GridConstructionError::ValueIsNotOfRectangularShape);
-        }
-
-        Ok(Self {
-            cells,
-            width,
-            height,
-        })
-    }
-}
-
-pub struct GridIterator<'a> {
-    index: usize,
-    grid: &'a Grid,
-}
-
-impl<'a> Iterator for GridIterator<'a> {
-    type Item = (Coordinate, &'a Cell);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index < self.grid.cells.len() {
-            let x = self.index % self.grid.width;
-            let y = (self.index - x) / self.grid.width;
-            let cell = &self.grid.cells[self.index];
-            self.index += 1;
-            Some((Coordinate { x, y }, cell))
-        } else {
-            None
+impl From<&Cell> for char {
+    fn from(value: &Cell) -> Self {
+        match value {
+            Cell::PaperRoll => '@',
+            Cell::Empty => '.',
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_core::ParseError;
+
     use super::*;
 
-    const INPUT: &str = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
+    const INPUT: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn parse_input_reports_invalid_cells() {
+        let error = Grid::try_from("..@\n.x.\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 2, "x", "expected `@` or `.`"), error);
+    }
 
     #[test]
     fn parse_input_works() {
@@ -152,6 +62,12 @@ mod tests {
             PaperRoll, PaperRoll, PaperRoll, PaperRoll, PaperRoll, Empty, PaperRoll, Empty,
             PaperRoll, Empty, PaperRoll, PaperRoll, PaperRoll, Empty, PaperRoll, Empty,
         ];
-        assert_eq!(expected_cells, grid.cells);
+        assert_eq!(expected_cells, grid.cells());
+    }
+
+    #[test]
+    fn display_gives_the_input() {
+        let grid = Grid::try_from(INPUT).expect("input should be rectangular");
+        assert_eq!(INPUT, grid.to_string());
     }
 }
@@ -0,0 +1,89 @@
+mod grid;
+
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+pub use grid::*;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed = Grid;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::try_from(input)
+    }
+
+    fn part_one(grid: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(paper_roll_locations_accessable_by_forklift(grid)
+            .len()
+            .into())
+    }
+
+    fn part_two(grid: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(
+            num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls(
+                &mut grid.clone(),
+            )
+            .into(),
+        )
+    }
+}
+
+impl Generator for Day04 {
+    /// A `size`x`size` grid, about 60% of it paper rolls.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let row = (0..size)
+                    .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+                    .collect::<String>();
+                row + "\n"
+            })
+            .collect()
+    }
+}
+
+const PAPER_ROLL_ACCESSABLE_THRESHOLD: u32 = 3;
+
+pub fn num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls(
+    grid: &mut Grid,
+) -> usize {
+    // a loop instead of recursion, so that large grids cannot overflow the stack
+    let mut num_of_removed_paper_rolls = 0;
+
+    loop {
+        let accessable_paper_rolls_locations = paper_roll_locations_accessable_by_forklift(grid);
+
+        if accessable_paper_rolls_locations.is_empty() {
+            return num_of_removed_paper_rolls;
+        }
+
+        for coord in accessable_paper_rolls_locations.iter() {
+            grid.set(coord, Cell::Empty)
+                .expect("coord should be in grid");
+        }
+
+        num_of_removed_paper_rolls += accessable_paper_rolls_locations.len();
+    }
+}
+
+pub fn paper_roll_locations_accessable_by_forklift(grid: &Grid) -> Vec<Coordinate> {
+    let mut result = Vec::new();
+
+    for (coord, cell) in grid.iter() {
+        if *cell == Cell::PaperRoll
+            && count_neighbors(grid, &coord) <= PAPER_ROLL_ACCESSABLE_THRESHOLD
+        {
+            result.push(coord);
+        }
+    }
+
+    result
+}
+
+fn count_neighbors(grid: &Grid, coord: &Coordinate) -> u32 {
+    grid.neighbors8(coord)
+        .filter(|(_, cell)| **cell == Cell::PaperRoll)
+        .count() as u32
+}
@@ -0,0 +1,58 @@
+use day_04::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day04);
+aoc_core::generator_tests!(Day04);
+
+#[test]
+fn num_of_paper_rolls_accessable_by_forklift_works() {
+    let grid = Grid::try_from(INPUT).expect("input should be rectangular");
+    let result = paper_roll_locations_accessable_by_forklift(&grid);
+    let expected = vec![
+        Coordinate { x: 2, y: 0 },
+        Coordinate { x: 3, y: 0 },
+        Coordinate { x: 5, y: 0 },
+        Coordinate { x: 6, y: 0 },
+        Coordinate { x: 8, y: 0 },
+        Coordinate { x: 0, y: 1 },
+        Coordinate { x: 6, y: 2 },
+        Coordinate { x: 0, y: 4 },
+        Coordinate { x: 9, y: 4 },
+        Coordinate { x: 0, y: 7 },
+        Coordinate { x: 0, y: 9 },
+        Coordinate { x: 2, y: 9 },
+        Coordinate { x: 8, y: 9 },
+    ];
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn accessable_paper_rolls_can_be_highlighted() {
+    let grid = Grid::try_from(INPUT).expect("input should be rectangular");
+    let accessable = paper_roll_locations_accessable_by_forklift(&grid);
+    let expected = "\
+..xx.xx@x.
+x@@.@.@.@@
+@@@@@.x.@@
+@.@@@@..@.
+x@.@@@@.@x
+.@@@@@@@.@
+.@.@.@.@@@
+x.@@@.@@@@
+.@@@@@@@@.
+x.x.@@@.x.
+";
+    assert_eq!(
+        expected,
+        grid.render_highlighted(|cell| char::from(cell), &accessable, 'x')
+    );
+}
+
+#[test]
+fn num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls_works() {
+    let mut grid = Grid::try_from(INPUT).expect("input should be rectangular");
+    let result = num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls(&mut grid);
+    let expected = 43;
+    assert_eq!(expected, result);
+}
@@ -1,20 +1,17 @@
 use std::ops::RangeInclusive;
 
+use aoc_core::ParseError;
+
 pub type IngredientId = u64;
 
+#[derive(Debug)]
 pub struct Inventory {
     pub fresh_ingredient_id_ranges: Vec<RangeInclusive<IngredientId>>,
     pub available_ingredient_ids: Vec<IngredientId>,
 }
 
-#[derive(Debug)]
-pub enum InventoryCreationError {
-    InvalidIngredientIdRange,
-    InvalidIngredientId,
-}
-
 impl TryFrom<&str> for Inventory {
-    type Error = InventoryCreationError;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut id_ranges = Vec::new();
@@ -27,23 +24,20 @@ impl TryFrom<&str> for Inventory {
                 continue;
             }
 
+            let line = line.trim();
+            let parse_id = |id: &str| {
+                id.parse::<IngredientId>()
+                    .map_err(|_| ParseError::at(value, id, "expected an ingredient ID"))
+            };
+
             if parsing_ranges {
-                if let Some((range_start, range_end)) = line.trim().split_once("-") {
-                    match (
-                        range_start.parse::<IngredientId>(),
-                        range_end.parse::<IngredientId>(),
-                    ) {
-                        (Ok(range_start), Ok(range_end)) => id_ranges.push(range_start..=range_end),
-                        _ => return Err(InventoryCreationError::InvalidIngredientIdRange),
-                    }
-                } else {
-                    return Err(InventoryCreationError::InvalidIngredientIdRange);
-                }
+                let Some((range_start, range_end)) = line.split_once("-") else {
+                    return Err(ParseError::at(value, line, "expected a range `start-end`"));
+                };
+
+                id_ranges.push(parse_id(range_start)?..=parse_id(range_end)?);
             } else {
-                match line.trim().parse::<IngredientId>() {
-                    Err(_) => return Err(InventoryCreationError::InvalidIngredientId),
-                    Ok(id) => available_ids.push(id),
-                }
+                available_ids.push(parse_id(line)?);
             }
         }
 
@@ -58,7 +52,7 @@ impl TryFrom<&str> for Inventory {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
+    const INPUT: &str = include_str!("../examples/1.txt");
 
     #[test]
     fn inventory_parsing_works() {
@@ -71,4 +65,19 @@ mod tests {
         assert_eq!(expected_ranges, inventory.fresh_ingredient_id_ranges);
         assert_eq!(expected_available_ids, inventory.available_ingredient_ids);
     }
+
+    #[test]
+    fn inventory_parsing_reports_invalid_ids() {
+        let error = Inventory::try_from("3-5\n10:14\n\n1\n").unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 1, "10:14", "expected a range `start-end`"),
+            error
+        );
+
+        let error = Inventory::try_from("3-5\n\n1\n-8\n").unwrap_err();
+        assert_eq!(
+            ParseError::new(4, 1, "-8", "expected an ingredient ID"),
+            error
+        );
+    }
 }
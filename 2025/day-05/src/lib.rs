@@ -0,0 +1,91 @@
+mod inventory;
+
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+pub use inventory::*;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed = Inventory;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Inventory::try_from(input)
+    }
+
+    fn part_one(inventory: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(num_of_fresh_and_available_ingredients(inventory).into())
+    }
+
+    fn part_two(inventory: &Self::Parsed) -> Result<Answer, SolveError> {
+        num_of_possible_fresh_ingredients(inventory).map(Answer::from)
+    }
+}
+
+impl Generator for Day05 {
+    /// `size` overlapping ranges of fresh IDs and `size` available IDs.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let start = rng.range(1..=999_999_999_999);
+            input += &format!("{start}-{}\n", start + rng.below(1_000_000_000));
+        }
+
+        input.push('\n');
+
+        for _ in 0..size {
+            input += &format!("{}\n", rng.range(1..=999_999_999_999));
+        }
+
+        input
+    }
+}
+
+pub fn num_of_fresh_and_available_ingredients(inventory: &Inventory) -> usize {
+    inventory
+        .available_ingredient_ids
+        .iter()
+        .filter(|id| {
+            inventory
+                .fresh_ingredient_id_ranges
+                .iter()
+                .any(|range| range.contains(id))
+        })
+        .count()
+}
+
+pub fn num_of_possible_fresh_ingredients(inventory: &Inventory) -> Result<u64, SolveError> {
+    // Creating a hashset over all ids allowed by the ranges would be too costly.
+    // It would require too much memory.
+    // Sorting the ranges is OK, because there a much much fewer ranges then possible values in those ranges.
+
+    let mut ranges_sorted_by_start = inventory.fresh_ingredient_id_ranges.clone();
+    ranges_sorted_by_start.sort_by_key(|r| *r.start());
+
+    let mut num: u64 = 0;
+    let mut latest_highest_id: Option<IngredientId> = None;
+
+    // the sum is checked, because a single range over all IDs already has one ID too many
+    for range in ranges_sorted_by_start
+        .iter()
+        .filter(|range| !range.is_empty())
+    {
+        let start = *range.start();
+        let end = *range.end();
+
+        let new_ids = match latest_highest_id {
+            Some(latest_highest_id) if end <= latest_highest_id => Some(0),
+            Some(latest_highest_id) if start <= latest_highest_id => Some(end - latest_highest_id),
+            _ => (end - start).checked_add(1),
+        };
+
+        num = new_ids
+            .and_then(|new_ids| num.checked_add(new_ids))
+            .ok_or(SolveError::Overflow)?;
+        latest_highest_id = Some(latest_highest_id.map_or(end, |latest| latest.max(end)));
+    }
+
+    Ok(num)
+}
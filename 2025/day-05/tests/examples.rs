@@ -0,0 +1,38 @@
+use aoc_core::SolveError;
+use day_05::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day05);
+aoc_core::generator_tests!(Day05);
+
+#[test]
+fn num_of_fresh_and_available_ingredients_works() {
+    let inventory = Inventory::try_from(INPUT).expect("inventory should be valid");
+    let result = num_of_fresh_and_available_ingredients(&inventory);
+    let expected = 3;
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn num_of_possible_fresh_ingredients_works() {
+    let inventory = Inventory::try_from(INPUT).expect("inventory should be valid");
+    let result = num_of_possible_fresh_ingredients(&inventory);
+    let expected = Ok(14);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn num_of_possible_fresh_ingredients_handles_edge_ranges() {
+    let inventory = Inventory::try_from("0-2\n5-3\n2-2\n\n").expect("inventory should be valid");
+    assert_eq!(Ok(3), num_of_possible_fresh_ingredients(&inventory));
+
+    let inventory = Inventory {
+        fresh_ingredient_id_ranges: vec![0..=IngredientId::MAX],
+        available_ingredient_ids: vec![],
+    };
+    assert_eq!(
+        Err(SolveError::Overflow),
+        num_of_possible_fresh_ingredients(&inventory)
+    );
+}
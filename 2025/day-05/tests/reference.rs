@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use day_05::*;
+use proptest::prelude::*;
+
+/// Collects every ID of every range into a set.
+fn num_of_possible_fresh_ingredients_in_a_set(inventory: &Inventory) -> u64 {
+    inventory
+        .fresh_ingredient_id_ranges
+        .iter()
+        .flat_map(|range| range.clone())
+        .collect::<HashSet<_>>()
+        .len() as u64
+}
+
+// empty ranges like `5-3` are included on purpose
+fn inventory() -> impl Strategy<Value = Inventory> {
+    prop::collection::vec((0_u64..200, 0_u64..200), 0..20).prop_map(|ranges| Inventory {
+        fresh_ingredient_id_ranges: ranges.into_iter().map(|(start, end)| start..=end).collect(),
+        available_ingredient_ids: Vec::new(),
+    })
+}
+
+proptest! {
+    #[test]
+    fn num_of_possible_fresh_ingredients_matches_a_set(inventory in inventory()) {
+        prop_assert_eq!(
+            Ok(num_of_possible_fresh_ingredients_in_a_set(&inventory)),
+            num_of_possible_fresh_ingredients(&inventory)
+        );
+    }
+}
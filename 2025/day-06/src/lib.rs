@@ -0,0 +1,115 @@
+mod worksheet;
+
+use std::iter::zip;
+
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+pub use worksheet::*;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Worksheet::try_from(input)
+    }
+
+    fn part_one(worksheet: &Self::Parsed) -> Result<Answer, SolveError> {
+        solve_part_one(worksheet).map(Answer::from)
+    }
+
+    fn part_two(worksheet: &Self::Parsed) -> Result<Answer, SolveError> {
+        solve_part_two(worksheet).map(Answer::from)
+    }
+}
+
+const GENERATED_NUMBER_LINES: usize = 4;
+
+impl Generator for Day06 {
+    /// `size` problems of four numbers with up to three digits each.
+    fn generate(size: usize, rng: &mut Rng) -> String {
+        let mut lines = vec![Vec::new(); GENERATED_NUMBER_LINES + 1];
+
+        for _ in 0..size {
+            let width = rng.range(1..=3) as usize;
+
+            // the widest number makes sure that every column of the problem has a digit,
+            // sorting the lengths keeps the digits of every column together
+            let mut lengths = (0..GENERATED_NUMBER_LINES)
+                .map(|_| rng.range(1..=width as u64) as usize)
+                .collect::<Vec<_>>();
+            lengths[0] = width;
+            lengths.sort();
+
+            if rng.chance(0.5) {
+                lengths.reverse();
+            }
+
+            let align_left = rng.chance(0.5);
+
+            for (line, length) in zip(lines.iter_mut(), lengths) {
+                let number = (0..length)
+                    .map(|index| {
+                        let digit = if index == 0 {
+                            rng.range(1..=9)
+                        } else {
+                            rng.below(10)
+                        };
+                        char::from(b'0' + digit as u8)
+                    })
+                    .collect::<String>();
+
+                line.push(if align_left {
+                    format!("{number:<width$}")
+                } else {
+                    format!("{number:>width$}")
+                });
+            }
+
+            let operator = rng.choose(&['+', '*']);
+            lines[GENERATED_NUMBER_LINES].push(format!("{operator:<width$}"));
+        }
+
+        lines.iter().map(|line| line.join(" ") + "\n").collect()
+    }
+}
+
+pub fn solve_part_one(worksheet: &Worksheet) -> Result<u64, SolveError> {
+    let mut result: u64 = 0;
+
+    for (index, operator) in worksheet.operators.iter().enumerate() {
+        let elements_to_operate_on = worksheet
+            .number_lines_part_one
+            .iter()
+            .map(|elements| elements[index]);
+
+        result = result
+            .checked_add(apply(operator, elements_to_operate_on)?)
+            .ok_or(SolveError::Overflow)?;
+    }
+
+    Ok(result)
+}
+
+pub fn solve_part_two(worksheet: &Worksheet) -> Result<u64, SolveError> {
+    zip(&worksheet.number_columns_part_two, &worksheet.operators).try_fold(
+        0_u64,
+        |result, (numbers, operator)| {
+            result
+                .checked_add(apply(operator, numbers.iter().copied())?)
+                .ok_or(SolveError::Overflow)
+        },
+    )
+}
+
+fn apply(operator: &Operator, numbers: impl Iterator<Item = u64>) -> Result<u64, SolveError> {
+    let mut numbers = numbers;
+
+    match operator {
+        Operator::Addition => numbers.try_fold(0, u64::checked_add),
+        Operator::Multiplication => numbers.try_fold(1, u64::checked_mul),
+    }
+    .ok_or(SolveError::Overflow)
+}
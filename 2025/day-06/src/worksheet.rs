@@ -1,5 +1,8 @@
 use std::fmt::Debug;
 
+use aoc_core::ParseError;
+
+#[derive(Debug)]
 pub struct Worksheet {
     pub number_lines_part_one: Vec<Vec<u64>>,
     pub number_columns_part_two: Vec<Vec<u64>>,
@@ -12,17 +15,14 @@ pub enum Operator {
     Multiplication,
 }
 
-#[derive(Debug)]
-pub struct WorkspaceCreationError;
-
 impl TryFrom<&str> for Worksheet {
-    type Error = WorkspaceCreationError;
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut lines_iter = value.lines();
 
         let Some(operators_line) = lines_iter.next_back() else {
-            return Err(WorkspaceCreationError);
+            return Err(ParseError::at(value, value, "expected a line of operators"));
         };
 
         let number_lines = lines_iter.collect::<Vec<&str>>();
@@ -36,29 +36,53 @@ impl TryFrom<&str> for Worksheet {
 
         // --- compute part one ----------------------------------------
 
-        for (index, character) in operators_line.chars().enumerate() {
-            match character {
-                '+' => {
-                    column_start_indecies.push(index);
-                    operators.push(Operator::Addition);
-                }
-                '*' => {
-                    column_start_indecies.push(index);
-                    operators.push(Operator::Multiplication);
-                }
-                ' ' => {}
-                _ => return Err(WorkspaceCreationError),
+        for (index, (offset, character)) in operators_line.char_indices().enumerate() {
+            let operator_text = &operators_line[offset..offset + character.len_utf8()];
+
+            let operator = match character {
+                '+' => Operator::Addition,
+                '*' => Operator::Multiplication,
+                ' ' => continue,
+                _ => return Err(ParseError::at(value, operator_text, "expected `+` or `*`")),
             };
+
+            // columns are separated by a column of spaces, so operators cannot be adjacent
+            if column_start_indecies
+                .last()
+                .is_some_and(|last_index| index < last_index + 2)
+            {
+                let reason = "expected a space between two operators";
+                return Err(ParseError::at(value, operator_text, reason));
+            }
+
+            column_start_indecies.push(index);
+            operators.push(operator);
         }
 
+        let expected_numbers = format!("expected {} numbers", operators.len());
+
         let number_lines_part_one = number_lines
             .iter()
             .map(|line| {
-                line.split_ascii_whitespace()
-                    .filter_map(|element| element.parse::<u64>().ok())
-                    .collect::<Vec<_>>()
+                let elements = line.split_ascii_whitespace().collect::<Vec<_>>();
+
+                if let Some(element) = elements.get(operators.len()) {
+                    return Err(ParseError::at(value, element, &expected_numbers));
+                } else if elements.len() < operators.len() {
+                    let end = &line[line.len()..];
+                    return Err(ParseError::at(value, end, &expected_numbers));
+                }
+
+                elements
+                    .iter()
+                    .map(|element| {
+                        element
+                            .parse::<u64>()
+                            .map_err(|_| ParseError::at(value, element, "expected a number"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>, _>>()?;
 
         // --- compute part two ----------------------------------------
 
@@ -78,15 +102,29 @@ impl TryFrom<&str> for Worksheet {
                 let mut number_string = String::with_capacity(number_lines.len());
 
                 for line in number_lines.iter() {
-                    number_string.push(line.to_string().chars().nth(i).unwrap());
+                    let Some((offset, digit)) = line.char_indices().nth(i) else {
+                        let end = &line[line.len()..];
+                        return Err(ParseError::at(value, end, "expected a digit or space"));
+                    };
+
+                    if !digit.is_ascii_digit() && digit != ' ' {
+                        let digit = &line[offset..offset + digit.len_utf8()];
+                        return Err(ParseError::at(value, digit, "expected a digit or space"));
+                    }
+
+                    number_string.push(digit);
                 }
 
-                column_numbers.push(
-                    number_string
-                        .trim()
-                        .parse::<u64>()
-                        .expect("number should be"),
-                );
+                let Ok(number) = number_string.trim().parse::<u64>() else {
+                    let column = &operators_line[i..i];
+                    return Err(ParseError::at(
+                        value,
+                        column,
+                        "expected a number in this column",
+                    ));
+                };
+
+                column_numbers.push(number);
             }
 
             number_columns_part_two.push(column_numbers);
@@ -104,7 +142,7 @@ impl TryFrom<&str> for Worksheet {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+    const INPUT: &str = include_str!("../examples/1.txt");
 
     #[test]
     fn parse_input_works() {
@@ -138,4 +176,37 @@ mod tests {
         );
         assert_eq!(expected_operators, worksheet.operators);
     }
+
+    #[test]
+    fn parse_input_reports_invalid_worksheets() {
+        let error = Worksheet::try_from("").unwrap_err();
+        assert_eq!(
+            ParseError::new(1, 1, "", "expected a line of operators"),
+            error
+        );
+
+        let error = Worksheet::try_from("12 3\n45 6\n*  -\n").unwrap_err();
+        assert_eq!(ParseError::new(3, 4, "-", "expected `+` or `*`"), error);
+
+        let error = Worksheet::try_from("12 3\n45 6\n* +*\n").unwrap_err();
+        assert_eq!(
+            ParseError::new(3, 4, "*", "expected a space between two operators"),
+            error
+        );
+
+        let error = Worksheet::try_from("12 3\n4x 6\n*  +\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 1, "4x", "expected a number"), error);
+
+        let error = Worksheet::try_from("12 3 4\n45 6\n*  +\n").unwrap_err();
+        assert_eq!(ParseError::new(1, 6, "4", "expected 2 numbers"), error);
+
+        let error = Worksheet::try_from("12 3\n45  \n*  +\n").unwrap_err();
+        assert_eq!(ParseError::new(2, 5, "", "expected 2 numbers"), error);
+
+        let error = Worksheet::try_from("12 3\n4 6\n*  +\n").unwrap_err();
+        assert_eq!(
+            ParseError::new(2, 4, "", "expected a digit or space"),
+            error
+        );
+    }
 }
@@ -0,0 +1,30 @@
+use aoc_core::SolveError;
+use day_06::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(Day06);
+aoc_core::generator_tests!(Day06);
+
+#[test]
+fn solve_part_one_works() {
+    let worksheet = Worksheet::try_from(INPUT).expect("worksheet should be valid");
+    let answer = solve_part_one(&worksheet);
+    let expected = Ok(4277556);
+    assert_eq!(expected, answer);
+}
+
+#[test]
+fn solve_part_two_works() {
+    let worksheet = Worksheet::try_from(INPUT).expect("worksheet should be valid");
+    let answer = solve_part_two(&worksheet);
+    let expected = Ok(3263827);
+    assert_eq!(expected, answer);
+}
+
+#[test]
+fn solve_reports_overflows() {
+    let worksheet = Worksheet::try_from("4294967296 1\n4294967296 2\n*          +\n")
+        .expect("worksheet should be valid");
+    assert_eq!(Err(SolveError::Overflow), solve_part_one(&worksheet));
+}
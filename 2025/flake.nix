@@ -29,6 +29,7 @@
         devShells.default = craneLib.devShell {
           packages = with pkgs; [
             bacon
+            cargo-fuzz
           ];
         };
 
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(rotations) = day_01::parse_input(input) {
+        let _ = day_01::find_password(&rotations);
+    }
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Solving is left out on purpose: it enumerates every ID of every range,
+// which takes forever for the huge ranges the fuzzer comes up with.
+fuzz_target!(|input: &str| {
+    let _ = day_02::parse_input(input);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(battery_banks) = day_03::parse_input(input) {
+        let _ = day_03::max_joltage(&battery_banks, 2);
+        let _ = day_03::max_joltage(&battery_banks, 12);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use day_04::{
+    Grid, num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls,
+    paper_roll_locations_accessable_by_forklift,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(mut grid) = Grid::try_from(input) {
+        paper_roll_locations_accessable_by_forklift(&grid);
+        num_of_paper_roll_locations_accessable_by_forklift_when_removing_rolls(&mut grid);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use day_05::{
+    Inventory, num_of_fresh_and_available_ingredients, num_of_possible_fresh_ingredients,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(inventory) = Inventory::try_from(input) {
+        num_of_fresh_and_available_ingredients(&inventory);
+        let _ = num_of_possible_fresh_ingredients(&inventory);
+    }
+});
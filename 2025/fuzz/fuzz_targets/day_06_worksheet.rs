@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_06::{Worksheet, solve_part_one, solve_part_two};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(worksheet) = Worksheet::try_from(input) {
+        let _ = solve_part_one(&worksheet);
+        let _ = solve_part_two(&worksheet);
+    }
+});
@@ -0,0 +1,32 @@
+use aoc_core::{Answer, Generator, ParseError, Rng, Solution, SolveError};
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(parse_input(input))
+    }
+
+    fn part_one(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
+
+    fn part_two(_parsed: &Self::Parsed) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
+}
+
+impl Generator for DayXX {
+    /// `size` lines of a valid input.
+    fn generate(size: usize, _rng: &mut Rng) -> String {
+        "\n".repeat(size)
+    }
+}
+
+pub fn parse_input(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
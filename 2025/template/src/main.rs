@@ -1,29 +1,7 @@
-fn main() {
-    let input = include_str!("../input.txt");
-    let parsed = parse_input(input);
+use std::process::ExitCode;
 
-    let part_one_answer = 0;
-    let part_two_answer = 0;
+use day_xx::DayXX;
 
-    println!("answers:");
-    println!(" - part one: {part_one_answer}");
-    println!(" - part two: {part_two_answer}");
-}
-
-fn parse_input(input: &str) -> () {
-    ()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "";
-
-    #[test]
-    fn parse_input_works() {
-        let parsed = parse_input(INPUT);
-        let expected = ();
-        assert_eq!(expected, parsed);
-    }
+fn main() -> ExitCode {
+    aoc_core::run::<DayXX>(aoc_core::default_input!())
 }
@@ -0,0 +1,13 @@
+use day_xx::*;
+
+const INPUT: &str = include_str!("../examples/1.txt");
+
+aoc_core::example_tests!(DayXX);
+aoc_core::generator_tests!(DayXX);
+
+#[test]
+fn parse_input_works() {
+    let parsed = parse_input(INPUT);
+    let expected: Vec<String> = vec![];
+    assert_eq!(expected, parsed);
+}